@@ -0,0 +1,211 @@
+import { type BigIntable } from './types.js'
+import { InvalidDigitError, InvalidQuantityError } from './errors.js'
+
+/**
+ * A collection of functions to convert BigInts to and from human readable strings
+ */
+export const BigIntFormat = {
+  /**
+   * Formats a number in base 10 inserting a separator between every group of digits
+   * @param value - The value to format
+   * @param separator - The string inserted between groups, defaults to `,`
+   * @param groupSize - The number of digits per group, defaults to 3
+   * @returns The grouped decimal representation of the input
+   * @throws RangeError if groupSize is not a positive integer
+   * @example
+   * ```ts
+   * BigIntFormat.formatDecimalGrouped(1234567n) // '1,234,567'
+   * BigIntFormat.formatDecimalGrouped(-1234567n, '.') // '-1.234.567'
+   * BigIntFormat.formatDecimalGrouped(12345678n, ' ', 4) // '1234 5678'
+   * ```
+   */
+  formatDecimalGrouped (value: BigIntable, separator = ',', groupSize = 3): string {
+    if (!Number.isInteger(groupSize) || groupSize < 1) {
+      throw new RangeError('BigIntFormat: group size must be a positive integer')
+    }
+    const v = BigInt(value)
+    const digits = (v < 0n ? -v : v).toString()
+    const head = digits.length % groupSize || groupSize
+    let toRet = digits.slice(0, head)
+    for (let i = head; i < digits.length; i += groupSize) {
+      toRet += separator + digits.slice(i, i + groupSize)
+    }
+    return v < 0n ? `-${toRet}` : toRet
+  },
+
+  /**
+   * Parses a decimal string into an integer scaled by 10 raised to the given number of decimals
+   * @param value - The decimal string to parse (e.g. `'1.5'`, `'-0.001'`)
+   * @param decimals - The number of decimal places of the unit
+   * @returns The scaled integer
+   * @throws SyntaxError if value is not a valid decimal string
+   * @throws RangeError if value has more significant fractional digits than decimals
+   * @example
+   * ```ts
+   * BigIntFormat.parseUnits('1.5', 18) // 1500000000000000000n
+   * BigIntFormat.parseUnits('-0.25', 2) // -25n
+   * ```
+   */
+  parseUnits (value: string, decimals: number): bigint {
+    assertDecimals(decimals)
+    const match = /^([+-]?)(\d*)(?:\.(\d*))?$/.exec(value)
+    if (match === null || (match[2] === '' && (match[3] ?? '') === '')) {
+      throw new SyntaxError(`BigIntFormat: cannot parse ${value} as a decimal number`)
+    }
+    const fraction = (match[3] ?? '').replace(/0+$/, '')
+    if (fraction.length > decimals) {
+      throw new RangeError(`BigIntFormat: ${value} has more than ${decimals} decimals`)
+    }
+    const toRet = BigInt((match[2] || '0') + fraction.padEnd(decimals, '0'))
+    return match[1] === '-' ? -toRet : toRet
+  },
+
+  /**
+   * Formats an integer scaled by 10 raised to the given number of decimals as a decimal string.
+   * Trailing fractional zeros are omitted, as is the decimal point for integral results.
+   * @param value - The scaled integer
+   * @param decimals - The number of decimal places of the unit
+   * @returns The decimal representation
+   * @example
+   * ```ts
+   * BigIntFormat.formatUnits(1500000000000000000n, 18) // '1.5'
+   * BigIntFormat.formatUnits(-25n, 2) // '-0.25'
+   * BigIntFormat.formatUnits(100n, 2) // '1'
+   * ```
+   */
+  formatUnits (value: BigIntable, decimals: number): string {
+    assertDecimals(decimals)
+    const v = BigInt(value)
+    const digits = (v < 0n ? -v : v).toString().padStart(decimals + 1, '0')
+    const integer = digits.slice(0, digits.length - decimals)
+    const fraction = digits.slice(digits.length - decimals).replace(/0+$/, '')
+    const toRet = fraction === '' ? integer : `${integer}.${fraction}`
+    return v < 0n ? `-${toRet}` : toRet
+  },
+
+  /**
+   * Parses a number written in scientific or engineering notation into an integer
+   * @param value - The string to parse (e.g. `'1.23e24'`, `'-5E+3'`, `'1200e-2'`)
+   * @param maxExponent - The largest power of ten the digits may be scaled by, defaults to 1000.
+   * Bounds the work done on untrusted input such as `'1e50000000'`
+   * @returns The parsed integer
+   * @throws SyntaxError if value is not a valid number
+   * @throws RangeError if the value is not an integer once the exponent is applied or the exponent exceeds maxExponent
+   * @example
+   * ```ts
+   * BigIntFormat.parseScientific('1.23e24') // 1230000000000000000000000n
+   * BigIntFormat.parseScientific('1200e-2') // 12n
+   * BigIntFormat.parseScientific('1.5e0') // RangeError
+   * ```
+   */
+  parseScientific (value: string, maxExponent = 1000): bigint {
+    const match = /^([+-]?)(\d*)(?:\.(\d*))?(?:[eE]([+-]?\d+))?$/.exec(value)
+    if (match === null || (match[2] === '' && (match[3] ?? '') === '')) {
+      throw new SyntaxError(`BigIntFormat: cannot parse ${value} as a number`)
+    }
+    const fraction = match[3] ?? ''
+    const digits = match[2] + fraction
+    const exponent = Number(match[4] ?? '0') - fraction.length
+    if (exponent > maxExponent) {
+      throw new RangeError(`BigIntFormat: exponent of ${value} exceeds ${maxExponent}`)
+    }
+    let toRet: bigint
+    if (exponent >= 0) {
+      toRet = BigInt(digits) * 10n ** BigInt(exponent)
+    } else {
+      const cut = digits.length + exponent
+      if (!/^0*$/.test(digits.slice(Math.max(cut, 0)))) {
+        throw new RangeError(`BigIntFormat: ${value} is not an integer`)
+      }
+      toRet = cut > 0 ? BigInt(digits.slice(0, cut)) : 0n
+    }
+    return match[1] === '-' ? -toRet : toRet
+  },
+
+  /**
+   * Encodes a number as an Ethereum JSON-RPC QUANTITY: `0x` prefixed, lowercase, no leading zeros
+   * @param value - The value to encode
+   * @returns The QUANTITY string
+   * @throws RangeError if value is negative
+   * @example
+   * ```ts
+   * BigIntFormat.toQuantityHex(0n) // '0x0'
+   * BigIntFormat.toQuantityHex(1024n) // '0x400'
+   * ```
+   */
+  toQuantityHex (value: BigIntable): string {
+    const v = BigInt(value)
+    if (v < 0n) {
+      throw new RangeError(`BigIntFormat: QUANTITY cannot be negative, got ${v}`)
+    }
+    return `0x${v.toString(16)}`
+  },
+
+  /**
+   * Decodes an Ethereum JSON-RPC QUANTITY, strictly enforcing its encoding rules
+   * @param value - The QUANTITY string
+   * @returns The decoded value
+   * @throws InvalidQuantityError if the prefix is missing, there are no digits, there are leading zeros
+   * or a character other than a lowercase hexadecimal digit is found
+   * @example
+   * ```ts
+   * BigIntFormat.fromQuantityHex('0x400') // 1024n
+   * BigIntFormat.fromQuantityHex('0x0400') // InvalidQuantityError (leadingZero)
+   * BigIntFormat.fromQuantityHex('0x') // InvalidQuantityError (empty)
+   * ```
+   */
+  fromQuantityHex (value: string): bigint {
+    if (!value.startsWith('0x')) {
+      throw new InvalidQuantityError(value, 'missingPrefix')
+    }
+    const digits = value.slice(2)
+    if (digits === '') {
+      throw new InvalidQuantityError(value, 'empty')
+    }
+    if (!/^[0-9a-f]+$/.test(digits)) {
+      throw new InvalidQuantityError(value, 'invalidDigit')
+    }
+    if (digits.length > 1 && digits[0] === '0') {
+      throw new InvalidQuantityError(value, 'leadingZero')
+    }
+    return BigInt(value)
+  },
+
+  /**
+   * Leniently parses a hexadecimal string, as typically typed or pasted by users.
+   * An optional `0x`/`0X` prefix, odd lengths, mixed case, underscores and whitespace are all accepted.
+   * @param value - The string to parse
+   * @returns The parsed value
+   * @throws InvalidDigitError if any other character is found, reporting its position
+   * @throws SyntaxError if the string contains no digits
+   * @example
+   * ```ts
+   * BigIntFormat.parseHex('0xDEAD_beef') // 3735928559n
+   * BigIntFormat.parseHex(' f ff ') // 4095n
+   * BigIntFormat.parseHex('0x12g4') // InvalidDigitError, position 4
+   * ```
+   */
+  parseHex (value: string): bigint {
+    const start = value.search(/\S/)
+    const offset = /^0[xX]/.test(value.slice(start)) ? start + 2 : 0
+    let digits = ''
+    for (let i = offset; i < value.length; i++) {
+      const c = value[i]
+      if (/[0-9a-fA-F]/.test(c)) {
+        digits += c
+      } else if (c !== '_' && !/\s/.test(c)) {
+        throw new InvalidDigitError(value, i)
+      }
+    }
+    if (digits === '') {
+      throw new SyntaxError(`BigIntFormat: ${value} contains no hexadecimal digits`)
+    }
+    return BigInt(`0x${digits}`)
+  }
+}
+
+function assertDecimals (decimals: number): void {
+  if (!Number.isInteger(decimals) || decimals < 0) {
+    throw new RangeError('BigIntFormat: decimals must be a non-negative integer')
+  }
+}
@@ -1,4 +1,4 @@
-import { type BigIntable } from './types.js'
+import { type BigIntable, type RoundingMode } from './types.js'
 import { fillRandom } from './fillRandom.js'
 import {
   converter
@@ -134,6 +134,65 @@ export const BigIntMath = {
     return remainder * 2n >= BigInt(divisor) ? quotient + 1n : quotient
   },
 
+  /**
+   * Multiplies a number by 10 raised to the given number of decimals
+   * @param value - The value to scale
+   * @param decimals - The number of decimal places to shift by
+   * @returns The scaled value
+   * @throws RangeError if decimals is not a non-negative integer
+   * @example
+   * ```ts
+   * BigIntMath.scaleUp(15n, 17) // 1500000000000000000n
+   * ```
+   */
+  scaleUp (value: BigIntable, decimals: number): bigint {
+    return BigInt(value) * pow10(decimals)
+  },
+
+  /**
+   * Divides a number by 10 raised to the given number of decimals, rounding the result
+   * @param value - The value to scale
+   * @param decimals - The number of decimal places to shift by
+   * @param rounding - How to round when digits are dropped, defaults to `trunc`
+   * @returns The scaled value
+   * @throws RangeError if decimals is not a non-negative integer
+   * @example
+   * ```ts
+   * BigIntMath.scaleDown(1500000000000000000n, 18) // 1n
+   * BigIntMath.scaleDown(1500000000000000000n, 18, 'halfExpand') // 2n
+   * BigIntMath.scaleDown(-1500000000000000000n, 18, 'floor') // -2n
+   * ```
+   */
+  scaleDown (value: BigIntable, decimals: number, rounding: RoundingMode = 'trunc'): bigint {
+    if (!ROUNDING_MODES.includes(rounding)) {
+      throw new RangeError(`BigIntMath: unknown rounding mode ${rounding as string}`)
+    }
+    const divisor = pow10(decimals)
+    const { quotient, remainder } = BigIntMath.divide(value, divisor)
+    if (remainder === 0n) {
+      return quotient
+    }
+    const away = remainder < 0n ? quotient - 1n : quotient + 1n
+    const doubled = 2n * (remainder < 0n ? -remainder : remainder)
+    switch (rounding) {
+      case 'trunc':
+        return quotient
+      case 'expand':
+        return away
+      case 'floor':
+        return remainder < 0n ? away : quotient
+      case 'ceil':
+        return remainder > 0n ? away : quotient
+      case 'halfExpand':
+        return doubled >= divisor ? away : quotient
+      case 'halfEven':
+        if (doubled === divisor) {
+          return quotient % 2n === 0n ? quotient : away
+        }
+        return doubled > divisor ? away : quotient
+    }
+  },
+
   /**
    * Returns a random number between 0 and a given maximum
    * @param max - The maximum value of the random number
@@ -155,3 +214,12 @@ export const BigIntMath = {
     return converter.unsigned.be.toBigInt(buffer) % max
   }
 }
+
+const ROUNDING_MODES: RoundingMode[] = ['ceil', 'floor', 'expand', 'trunc', 'halfExpand', 'halfEven']
+
+function pow10 (decimals: number): bigint {
+  if (!Number.isInteger(decimals) || decimals < 0) {
+    throw new RangeError('BigIntMath: decimals must be a non-negative integer')
+  }
+  return 10n ** BigInt(decimals)
+}
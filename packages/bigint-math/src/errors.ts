@@ -0,0 +1,44 @@
+/**
+ * Reasons why a string was rejected as an Ethereum JSON-RPC QUANTITY
+ */
+export type InvalidQuantityReason = 'missingPrefix' | 'empty' | 'leadingZero' | 'invalidDigit'
+
+/**
+ * Error thrown when a string does not follow the Ethereum JSON-RPC QUANTITY encoding rules
+ */
+export class InvalidQuantityError extends SyntaxError {
+  /**
+   * The rule that was violated
+   */
+  readonly reason: InvalidQuantityReason
+
+  /**
+   * @param value - the rejected string
+   * @param reason - the rule that was violated
+   */
+  constructor (value: string, reason: InvalidQuantityReason) {
+    super(`BigIntFormat: ${value} is not a valid QUANTITY (${reason})`)
+    this.name = 'InvalidQuantityError'
+    this.reason = reason
+  }
+}
+
+/**
+ * Error thrown when a string contains a character that is not a valid digit
+ */
+export class InvalidDigitError extends SyntaxError {
+  /**
+   * Index of the offending character in the parsed string
+   */
+  readonly position: number
+
+  /**
+   * @param value - the rejected string
+   * @param position - index of the offending character
+   */
+  constructor (value: string, position: number) {
+    super(`BigIntFormat: invalid digit ${JSON.stringify(value[position])} at position ${position} in ${value}`)
+    this.name = 'InvalidDigitError'
+    this.position = position
+  }
+}
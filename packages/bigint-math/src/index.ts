@@ -1,2 +1,4 @@
 export * from './BigIntMath.js'
+export * from './BigIntFormat.js'
+export * from './errors.js'
 export * from './types.js'
@@ -1 +1,13 @@
 export type BigIntable = bigint | number | string
+
+/**
+ * Rounding strategy applied when a division leaves a remainder.
+ * Names follow the `roundingMode` option of `Intl.NumberFormat`:
+ * - `ceil`: towards positive infinity
+ * - `floor`: towards negative infinity
+ * - `expand`: away from zero
+ * - `trunc`: towards zero
+ * - `halfExpand`: to the nearest integer, ties away from zero
+ * - `halfEven`: to the nearest integer, ties to the even neighbour
+ */
+export type RoundingMode = 'ceil' | 'floor' | 'expand' | 'trunc' | 'halfExpand' | 'halfEven'
@@ -0,0 +1,129 @@
+import { describe, expect, it } from 'vitest'
+import { BigIntFormat, InvalidDigitError, InvalidQuantityError } from '@/index.js'
+
+describe('BigIntFormat', () => {
+  it('formatDecimalGrouped', () => {
+    expect(BigIntFormat.formatDecimalGrouped(0n)).eq('0')
+    expect(BigIntFormat.formatDecimalGrouped(123n)).eq('123')
+    expect(BigIntFormat.formatDecimalGrouped(1234n)).eq('1,234')
+    expect(BigIntFormat.formatDecimalGrouped(123456n)).eq('123,456')
+    expect(BigIntFormat.formatDecimalGrouped(1234567n)).eq('1,234,567')
+    expect(BigIntFormat.formatDecimalGrouped(-1234567n)).eq('-1,234,567')
+    expect(BigIntFormat.formatDecimalGrouped('1234567', '.')).eq('1.234.567')
+    expect(BigIntFormat.formatDecimalGrouped(12345678n, ' ', 4)).eq('1234 5678')
+    expect(BigIntFormat.formatDecimalGrouped(2n ** 64n, '_')).eq('18_446_744_073_709_551_616')
+  })
+  it('formatDecimalGrouped invalid group size', () => {
+    expect(() => BigIntFormat.formatDecimalGrouped(1n, ',', 0)).throws(RangeError)
+    expect(() => BigIntFormat.formatDecimalGrouped(1n, ',', 1.5)).throws(RangeError)
+  })
+  it('parseUnits', () => {
+    expect(BigIntFormat.parseUnits('1.5', 18)).eq(1500000000000000000n)
+    expect(BigIntFormat.parseUnits('1', 18)).eq(1000000000000000000n)
+    expect(BigIntFormat.parseUnits('.5', 1)).eq(5n)
+    expect(BigIntFormat.parseUnits('5.', 1)).eq(50n)
+    expect(BigIntFormat.parseUnits('-0.25', 2)).eq(-25n)
+    expect(BigIntFormat.parseUnits('+12', 0)).eq(12n)
+    expect(BigIntFormat.parseUnits('1.2500', 2)).eq(125n)
+    expect(() => BigIntFormat.parseUnits('1.255', 2)).throws(RangeError)
+    expect(() => BigIntFormat.parseUnits('', 2)).throws(SyntaxError)
+    expect(() => BigIntFormat.parseUnits('.', 2)).throws(SyntaxError)
+    expect(() => BigIntFormat.parseUnits('1e5', 2)).throws(SyntaxError)
+    expect(() => BigIntFormat.parseUnits('1', -1)).throws(RangeError)
+  })
+  it('formatUnits', () => {
+    expect(BigIntFormat.formatUnits(1500000000000000000n, 18)).eq('1.5')
+    expect(BigIntFormat.formatUnits(1n, 18)).eq('0.000000000000000001')
+    expect(BigIntFormat.formatUnits(-25n, 2)).eq('-0.25')
+    expect(BigIntFormat.formatUnits(100n, 2)).eq('1')
+    expect(BigIntFormat.formatUnits(0n, 2)).eq('0')
+    expect(BigIntFormat.formatUnits(123n, 0)).eq('123')
+  })
+  it('parseScientific', () => {
+    expect(BigIntFormat.parseScientific('1.23e24')).eq(1230000000000000000000000n)
+    expect(BigIntFormat.parseScientific('1.23E+24')).eq(1230000000000000000000000n)
+    expect(BigIntFormat.parseScientific('-5e3')).eq(-5000n)
+    expect(BigIntFormat.parseScientific('12.5e3')).eq(12500n)
+    expect(BigIntFormat.parseScientific('1200e-2')).eq(12n)
+    expect(BigIntFormat.parseScientific('0.0e-5')).eq(0n)
+    expect(BigIntFormat.parseScientific('42')).eq(42n)
+    expect(BigIntFormat.parseScientific('.5e1')).eq(5n)
+    expect(() => BigIntFormat.parseScientific('1.5e0')).throws(RangeError)
+    expect(() => BigIntFormat.parseScientific('1e-1')).throws(RangeError)
+    expect(() => BigIntFormat.parseScientific('e5')).throws(SyntaxError)
+    expect(() => BigIntFormat.parseScientific('1e')).throws(SyntaxError)
+    expect(() => BigIntFormat.parseScientific('0x10')).throws(SyntaxError)
+  })
+  it('parseScientific bounds the exponent', () => {
+    expect(BigIntFormat.parseScientific('1e1000')).eq(10n ** 1000n)
+    expect(BigIntFormat.parseScientific('1.5e1001')).eq(15n * 10n ** 1000n)
+    expect(BigIntFormat.parseScientific('1e20', 20)).eq(10n ** 20n)
+    expect(() => BigIntFormat.parseScientific('1e1001')).throws(RangeError)
+    expect(() => BigIntFormat.parseScientific('1e50000000')).throws(RangeError)
+    expect(() => BigIntFormat.parseScientific('0e50000000')).throws(RangeError)
+    expect(() => BigIntFormat.parseScientific('1e21', 20)).throws(RangeError)
+    expect(BigIntFormat.parseScientific('0e-50000000')).eq(0n)
+  })
+  it('toQuantityHex', () => {
+    expect(BigIntFormat.toQuantityHex(0n)).eq('0x0')
+    expect(BigIntFormat.toQuantityHex(1n)).eq('0x1')
+    expect(BigIntFormat.toQuantityHex(65n)).eq('0x41')
+    expect(BigIntFormat.toQuantityHex(1024n)).eq('0x400')
+    expect(BigIntFormat.toQuantityHex(2n ** 256n - 1n)).eq(`0x${'f'.repeat(64)}`)
+    expect(() => BigIntFormat.toQuantityHex(-1n)).throws(RangeError)
+  })
+  it('fromQuantityHex', () => {
+    expect(BigIntFormat.fromQuantityHex('0x0')).eq(0n)
+    expect(BigIntFormat.fromQuantityHex('0x41')).eq(65n)
+    expect(BigIntFormat.fromQuantityHex('0x400')).eq(1024n)
+    expect(BigIntFormat.fromQuantityHex('0xdeadbeef')).eq(0xdeadbeefn)
+    const reasons: Array<[string, string]> = [
+      ['400', 'missingPrefix'],
+      ['0X400', 'missingPrefix'],
+      ['', 'missingPrefix'],
+      ['0x', 'empty'],
+      ['0x0400', 'leadingZero'],
+      ['0x00', 'leadingZero'],
+      ['0xfg', 'invalidDigit'],
+      ['0x-1', 'invalidDigit'],
+      ['0x 1', 'invalidDigit'],
+      ['0xDeadBeef', 'invalidDigit'],
+      ['0xA', 'invalidDigit']
+    ]
+    for (const [value, reason] of reasons) {
+      expect(() => BigIntFormat.fromQuantityHex(value)).throws(InvalidQuantityError)
+      try {
+        BigIntFormat.fromQuantityHex(value)
+      } catch (e) {
+        expect((e as InvalidQuantityError).reason).eq(reason)
+      }
+    }
+  })
+  it('parseHex', () => {
+    expect(BigIntFormat.parseHex('ff')).eq(255n)
+    expect(BigIntFormat.parseHex('0xfff')).eq(4095n)
+    expect(BigIntFormat.parseHex('0XFfF')).eq(4095n)
+    expect(BigIntFormat.parseHex('0xDEAD_beef')).eq(0xdeadbeefn)
+    expect(BigIntFormat.parseHex(' de ad\tbe\nef ')).eq(0xdeadbeefn)
+    expect(BigIntFormat.parseHex('  0x1')).eq(1n)
+    expect(BigIntFormat.parseHex('0')).eq(0n)
+    expect(() => BigIntFormat.parseHex('')).throws(SyntaxError)
+    expect(() => BigIntFormat.parseHex('0x')).throws(SyntaxError)
+    expect(() => BigIntFormat.parseHex(' _ ')).throws(SyntaxError)
+    const positions: Array<[string, number]> = [
+      ['0x12g4', 4],
+      ['12g4', 2],
+      ['-1', 0],
+      ['0x0x1', 3],
+      ['  0x1 z', 6]
+    ]
+    for (const [value, position] of positions) {
+      expect(() => BigIntFormat.parseHex(value)).throws(InvalidDigitError)
+      try {
+        BigIntFormat.parseHex(value)
+      } catch (e) {
+        expect((e as InvalidDigitError).position).eq(position)
+      }
+    }
+  })
+})
@@ -1,5 +1,5 @@
 import { describe, expect, it } from 'vitest'
-import { BigIntMath } from '@/index.js'
+import { BigIntMath, type RoundingMode } from '@/index.js'
 
 describe('BigIntMath', () => {
   it('abs', () => {
@@ -53,4 +53,32 @@ describe('BigIntMath', () => {
     expect(BigIntMath.roundDivide(7, 2)).eq(4n)
     expect(BigIntMath.roundDivide(7, 2)).eq(4n)
   })
+
+  it('scaleUp', () => {
+    expect(BigIntMath.scaleUp(15n, 17)).eq(1500000000000000000n)
+    expect(BigIntMath.scaleUp(-1, 2)).eq(-100n)
+    expect(BigIntMath.scaleUp(7n, 0)).eq(7n)
+    expect(() => BigIntMath.scaleUp(1n, -1)).throws(RangeError)
+  })
+
+  it('scaleDown', () => {
+    expect(BigIntMath.scaleDown(1500n, 3)).eq(1n)
+    expect(BigIntMath.scaleDown(-1500n, 3)).eq(-1n)
+    expect(BigIntMath.scaleDown(1500n, 3, 'floor')).eq(1n)
+    expect(BigIntMath.scaleDown(-1500n, 3, 'floor')).eq(-2n)
+    expect(BigIntMath.scaleDown(1500n, 3, 'ceil')).eq(2n)
+    expect(BigIntMath.scaleDown(-1500n, 3, 'ceil')).eq(-1n)
+    expect(BigIntMath.scaleDown(1001n, 3, 'expand')).eq(2n)
+    expect(BigIntMath.scaleDown(-1001n, 3, 'expand')).eq(-2n)
+    expect(BigIntMath.scaleDown(1500n, 3, 'halfExpand')).eq(2n)
+    expect(BigIntMath.scaleDown(-1500n, 3, 'halfExpand')).eq(-2n)
+    expect(BigIntMath.scaleDown(1499n, 3, 'halfExpand')).eq(1n)
+    expect(BigIntMath.scaleDown(1500n, 3, 'halfEven')).eq(2n)
+    expect(BigIntMath.scaleDown(2500n, 3, 'halfEven')).eq(2n)
+    expect(BigIntMath.scaleDown(-2500n, 3, 'halfEven')).eq(-2n)
+    expect(BigIntMath.scaleDown(2501n, 3, 'halfEven')).eq(3n)
+    expect(BigIntMath.scaleDown(2000n, 3, 'expand')).eq(2n)
+    expect(() => BigIntMath.scaleDown(2000n, 3, 'up' as RoundingMode)).throws(RangeError)
+    expect(() => BigIntMath.scaleDown(2001n, 3, 'up' as RoundingMode)).throws(RangeError)
+  })
 })
@@ -0,0 +1,96 @@
+import { uncheckedConverter } from '../converter/index.js'
+
+const WORD_BYTES = 32
+
+/**
+ * Ethereum contract ABI codec for `uintN`/`intN` values, which are always padded to a 32 byte big-endian word.
+ * Signed values are sign extended (two's complement) to the full word.
+ */
+export const abi = {
+  /**
+   * Encodes a `uintN` value as a 32 byte word
+   * @param num - number to encode
+   * @param bits - the N in `uintN`, a multiple of 8 between 8 and 256, defaults to 256
+   * @returns Uint8Array of 32 bytes
+   * @throws RangeError if num does not fit in `uintN` or bits is invalid
+   * @example
+   * ```ts
+   * abi.encodeUint(1n) // Uint8Array [ 0x00, ..., 0x00, 0x01 ]
+   * ```
+   */
+  encodeUint (num: bigint, bits = 256): Uint8Array {
+    assertBits(bits)
+    if (num < 0n || num >= 1n << BigInt(bits)) {
+      throw new RangeError(`abi: ${num} is out of boundaries for uint${bits}`)
+    }
+    return uncheckedConverter.bigEndianToNewArray(num, WORD_BYTES)
+  },
+
+  /**
+   * Encodes an `intN` value as a sign extended 32 byte word
+   * @param num - number to encode
+   * @param bits - the N in `intN`, a multiple of 8 between 8 and 256, defaults to 256
+   * @returns Uint8Array of 32 bytes
+   * @throws RangeError if num does not fit in `intN` or bits is invalid
+   * @example
+   * ```ts
+   * abi.encodeInt(-1n, 8) // Uint8Array [ 0xff, ..., 0xff ]
+   * ```
+   */
+  encodeInt (num: bigint, bits = 256): Uint8Array {
+    assertBits(bits)
+    const limit = 1n << BigInt(bits - 1)
+    if (num < -limit || num >= limit) {
+      throw new RangeError(`abi: ${num} is out of boundaries for int${bits}`)
+    }
+    return uncheckedConverter.bigEndianToNewArray(BigInt.asUintN(WORD_BYTES * 8, num), WORD_BYTES)
+  },
+
+  /**
+   * Decodes a `uintN` value from a 32 byte word
+   * @param buf - buffer to read from
+   * @param bits - the N in `uintN`, a multiple of 8 between 8 and 256, defaults to 256
+   * @param offset - position of the word, defaults to 0
+   * @returns the decoded value
+   * @throws RangeError if the buffer is too short, bits is invalid or the padding bytes are not zero
+   */
+  decodeUint (buf: Uint8Array, bits = 256, offset = 0): bigint {
+    assertBits(bits)
+    const value = uncheckedConverter.arrayToBigEndian(readWord(buf, offset))
+    if (value >= 1n << BigInt(bits)) {
+      throw new RangeError(`abi: word at offset ${offset} is not a valid uint${bits}`)
+    }
+    return value
+  },
+
+  /**
+   * Decodes an `intN` value from a sign extended 32 byte word
+   * @param buf - buffer to read from
+   * @param bits - the N in `intN`, a multiple of 8 between 8 and 256, defaults to 256
+   * @param offset - position of the word, defaults to 0
+   * @returns the decoded value
+   * @throws RangeError if the buffer is too short, bits is invalid or the word is not properly sign extended
+   */
+  decodeInt (buf: Uint8Array, bits = 256, offset = 0): bigint {
+    assertBits(bits)
+    const value = BigInt.asIntN(WORD_BYTES * 8, uncheckedConverter.arrayToBigEndian(readWord(buf, offset)))
+    const limit = 1n << BigInt(bits - 1)
+    if (value < -limit || value >= limit) {
+      throw new RangeError(`abi: word at offset ${offset} is not a valid int${bits}`)
+    }
+    return value
+  }
+}
+
+function assertBits (bits: number): void {
+  if (!Number.isInteger(bits) || bits < 8 || bits > 256 || bits % 8 !== 0) {
+    throw new RangeError(`abi: invalid bit size ${bits}`)
+  }
+}
+
+function readWord (buf: Uint8Array, offset: number): Uint8Array {
+  if (offset < 0 || offset + WORD_BYTES > buf.length) {
+    throw new RangeError('abi: unexpected end of buffer')
+  }
+  return buf.subarray(offset, offset + WORD_BYTES)
+}
@@ -0,0 +1,60 @@
+/**
+ * Packs unsigned integers of arbitrary bit widths into a single bigint and back,
+ * for layouts that are not byte aligned (e.g. a 12-bit and a 52-bit field sharing a 64-bit slot).
+ *
+ * The first field occupies the most significant bits. The packed value can then be written
+ * with any of the converters, e.g. `converter.unsigned.be.toNewArray(packed, 8)`.
+ */
+export const bitfield = {
+  /**
+   * Packs the fields, first one in the most significant bits
+   * @param fields - list of `[value, bitWidth]` tuples
+   * @returns the packed value, spanning the sum of the bit widths
+   * @throws RangeError if a bit width is not a positive integer or a value does not fit its width
+   * @example
+   * ```ts
+   * bitfield.pack([[0xabcn, 12], [1n, 52]]) // 0xabc0000000000001n
+   * ```
+   */
+  pack (fields: Array<[bigint, number]>): bigint {
+    let toRet = 0n
+    for (const [value, bits] of fields) {
+      assertWidth(bits)
+      if (value < 0n || value >> BigInt(bits) !== 0n) {
+        throw new RangeError(`bitfield: value ${value} does not fit in ${bits} bits`)
+      }
+      toRet = (toRet << BigInt(bits)) | value
+    }
+    return toRet
+  },
+  /**
+   * Splits a packed value into its fields, first one read from the most significant bits
+   * @param packed - the value returned by {@link bitfield.pack}
+   * @param widths - bit width of each field
+   * @returns the value of each field
+   * @throws RangeError if a bit width is not a positive integer or packed does not fit the sum of the widths
+   * @example
+   * ```ts
+   * bitfield.unpack(0xabc0000000000001n, [12, 52]) // [ 0xabcn, 1n ]
+   * ```
+   */
+  unpack (packed: bigint, widths: number[]): bigint[] {
+    const toRet: bigint[] = new Array(widths.length)
+    let rest = packed
+    for (let i = widths.length - 1; i >= 0; i--) {
+      assertWidth(widths[i])
+      toRet[i] = BigInt.asUintN(widths[i], rest)
+      rest >>= BigInt(widths[i])
+    }
+    if (packed < 0n || rest !== 0n) {
+      throw new RangeError(`bitfield: ${packed} does not fit in ${widths.reduce((a, b) => a + b, 0)} bits`)
+    }
+    return toRet
+  }
+}
+
+function assertWidth (bits: number): void {
+  if (!Number.isInteger(bits) || bits <= 0) {
+    throw new RangeError(`bitfield: invalid bit width ${bits}`)
+  }
+}
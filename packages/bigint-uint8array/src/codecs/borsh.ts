@@ -0,0 +1,83 @@
+import { uncheckedConverter } from '../converter/index.js'
+
+const BORSH_BITS = [8, 16, 32, 64, 128, 256, 512]
+
+/**
+ * Borsh codec for fixed-width integers (Solana, NEAR): little-endian, two's complement for signed types.
+ * Besides the primitive `u8`..`u128`/`i8`..`i128` types, the 256 and 512 bit widths used by
+ * `U256`/`U512` newtypes are supported.
+ */
+export const borsh = {
+  /**
+   * Serializes an unsigned integer
+   * @param num - number to serialize
+   * @param bits - one of 8, 16, 32, 64, 128, 256 or 512
+   * @returns Uint8Array of bits / 8 bytes
+   * @throws RangeError if bits is not supported or num does not fit
+   * @example
+   * ```ts
+   * borsh.encodeUint(258n, 128) // Uint8Array [ 0x02, 0x01, 0x00, ... ]
+   * ```
+   */
+  encodeUint (num: bigint, bits: number): Uint8Array {
+    assertBits(bits)
+    if (num < 0n || num >= 1n << BigInt(bits)) {
+      throw new RangeError(`borsh: ${num} is out of boundaries for u${bits}`)
+    }
+    return uncheckedConverter.littleEndianToNewArray(num, bits / 8)
+  },
+
+  /**
+   * Serializes a signed integer
+   * @param num - number to serialize
+   * @param bits - one of 8, 16, 32, 64, 128, 256 or 512
+   * @returns Uint8Array of bits / 8 bytes
+   * @throws RangeError if bits is not supported or num does not fit
+   */
+  encodeInt (num: bigint, bits: number): Uint8Array {
+    assertBits(bits)
+    const limit = 1n << BigInt(bits - 1)
+    if (num < -limit || num >= limit) {
+      throw new RangeError(`borsh: ${num} is out of boundaries for i${bits}`)
+    }
+    return uncheckedConverter.littleEndianToNewArray(BigInt.asUintN(bits, num), bits / 8)
+  },
+
+  /**
+   * Deserializes an unsigned integer
+   * @param buf - buffer to read from
+   * @param bits - one of 8, 16, 32, 64, 128, 256 or 512
+   * @param offset - position of the value, defaults to 0
+   * @returns the deserialized value
+   * @throws RangeError if bits is not supported or the buffer is too short
+   */
+  decodeUint (buf: Uint8Array, bits: number, offset = 0): bigint {
+    return uncheckedConverter.arrayToLittleEndian(read(buf, bits, offset))
+  },
+
+  /**
+   * Deserializes a signed integer
+   * @param buf - buffer to read from
+   * @param bits - one of 8, 16, 32, 64, 128, 256 or 512
+   * @param offset - position of the value, defaults to 0
+   * @returns the deserialized value
+   * @throws RangeError if bits is not supported or the buffer is too short
+   */
+  decodeInt (buf: Uint8Array, bits: number, offset = 0): bigint {
+    return BigInt.asIntN(bits, uncheckedConverter.arrayToLittleEndian(read(buf, bits, offset)))
+  }
+}
+
+function assertBits (bits: number): void {
+  if (!BORSH_BITS.includes(bits)) {
+    throw new RangeError(`borsh: invalid integer size ${bits}`)
+  }
+}
+
+function read (buf: Uint8Array, bits: number, offset: number): Uint8Array {
+  assertBits(bits)
+  if (offset < 0 || offset + bits / 8 > buf.length) {
+    throw new RangeError('borsh: unexpected end of buffer')
+  }
+  return buf.subarray(offset, offset + bits / 8)
+}
@@ -0,0 +1,95 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { byteLength } from '../utils.js'
+
+const TAG_POSITIVE_BIGNUM = 0xc2
+const TAG_NEGATIVE_BIGNUM = 0xc3
+const MAJOR_BYTE_STRING = 0x40
+
+/**
+ * CBOR bignum codec (RFC 8949 section 3.4.3): tag 2 (positive) or tag 3 (negative, holding -1 - n)
+ * followed by a byte string with the minimal big-endian magnitude.
+ */
+export const cbor = {
+  /**
+   * Encodes a number as a CBOR bignum
+   * @param num - number to encode
+   * @returns Uint8Array holding the tag and the byte string
+   * @example
+   * ```ts
+   * cbor.encodeBignum(2n ** 64n) // Uint8Array [ 0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ]
+   * cbor.encodeBignum(-(2n ** 64n) - 1n) // Uint8Array [ 0xc3, 0x49, 0x01, 0x00, ... ]
+   * ```
+   */
+  encodeBignum (num: bigint): Uint8Array {
+    const magnitude = num < 0n ? -1n - num : num
+    const length = byteLength(magnitude)
+    const header = encodeHeader(length)
+    const toRet = new Uint8Array(1 + header.length + length)
+    toRet[0] = num < 0n ? TAG_NEGATIVE_BIGNUM : TAG_POSITIVE_BIGNUM
+    toRet.set(header, 1)
+    if (length > 0) {
+      uncheckedConverter.bigEndianToArray(magnitude, toRet.subarray(1 + header.length))
+    }
+    return toRet
+  },
+
+  /**
+   * Decodes a CBOR bignum, rejecting leading zero bytes and non minimal length headers
+   * @param buf - buffer to read from
+   * @param offset - position of the tag, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the item is truncated, is not a tag 2/3 byte string or is not minimally encoded
+   */
+  decodeBignum (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset + 2 > buf.length) {
+      throw new RangeError('cbor: unexpected end of buffer')
+    }
+    const tag = buf[offset]
+    if (tag !== TAG_POSITIVE_BIGNUM && tag !== TAG_NEGATIVE_BIGNUM) {
+      throw new RangeError(`cbor: expected tag 2 or 3, got 0x${tag.toString(16)}`)
+    }
+    const initial = buf[offset + 1]
+    if ((initial & 0xe0) !== MAJOR_BYTE_STRING) {
+      throw new RangeError('cbor: bignum tag must be followed by a byte string')
+    }
+    let start = offset + 2
+    let length = initial & 0x1f
+    if (length >= 24) {
+      if (length > 27) {
+        throw new RangeError('cbor: indefinite or reserved length is not allowed')
+      }
+      const lengthBytes = 1 << (length - 24)
+      if (start + lengthBytes > buf.length) {
+        throw new RangeError('cbor: unexpected end of buffer')
+      }
+      length = Number(uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + lengthBytes)))
+      if (encodeHeader(length).length !== lengthBytes + 1) {
+        throw new RangeError('cbor: length is not minimally encoded')
+      }
+      start += lengthBytes
+    }
+    if (start + length > buf.length) {
+      throw new RangeError('cbor: unexpected end of buffer')
+    }
+    if (length > 0 && buf[start] === 0) {
+      throw new RangeError('cbor: bignum has leading zeros')
+    }
+    const magnitude = length === 0 ? 0n : uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + length))
+    return {
+      value: tag === TAG_NEGATIVE_BIGNUM ? -1n - magnitude : magnitude,
+      bytesRead: start + length - offset
+    }
+  }
+}
+
+function encodeHeader (length: number): Uint8Array {
+  if (length < 24) {
+    return Uint8Array.of(MAJOR_BYTE_STRING | length)
+  }
+  const lengthBytes = length <= 0xff ? 1 : length <= 0xffff ? 2 : length <= 0xffffffff ? 4 : 8
+  const toRet = new Uint8Array(1 + lengthBytes)
+  toRet[0] = MAJOR_BYTE_STRING | (24 + Math.log2(lengthBytes))
+  uncheckedConverter.bigEndianToArray(BigInt(length), toRet.subarray(1))
+  return toRet
+}
@@ -0,0 +1,62 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+
+const UINT64_MAX = 2n ** 64n - 1n
+
+/**
+ * Bitcoin CompactSize codec: 1, 3, 5 or 9 byte little-endian unsigned integers,
+ * as used for lengths and counts in the P2P protocol and transaction serialization.
+ */
+export const compactSize = {
+  /**
+   * Encodes a number as CompactSize using the shortest form
+   * @param num - number to encode, between 0 and 2^64 - 1
+   * @returns Uint8Array
+   * @throws RangeError if num does not fit in 64 bits
+   * @example
+   * ```ts
+   * compactSize.encode(252n) // Uint8Array [ 0xfc ]
+   * compactSize.encode(253n) // Uint8Array [ 0xfd, 0xfd, 0x00 ]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    if (num < 0n || num > UINT64_MAX) {
+      throw new RangeError(`compactSize: ${num} is out of boundaries min=0 max=${UINT64_MAX}`)
+    }
+    if (num < 0xfdn) {
+      return Uint8Array.of(Number(num))
+    }
+    const width = num <= 0xffffn ? 2 : num <= 0xffffffffn ? 4 : 8
+    const toRet = new Uint8Array(width + 1)
+    toRet[0] = width === 2 ? 0xfd : width === 4 ? 0xfe : 0xff
+    uncheckedConverter.littleEndianToArray(num, toRet.subarray(1))
+    return toRet
+  },
+
+  /**
+   * Decodes a CompactSize number, rejecting non canonical (non shortest) encodings
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the number, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short or the encoding is not canonical
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset >= buf.length) {
+      throw new RangeError('compactSize: unexpected end of buffer')
+    }
+    const prefix = buf[offset]
+    if (prefix < 0xfd) {
+      return { value: BigInt(prefix), bytesRead: 1 }
+    }
+    const width = prefix === 0xfd ? 2 : prefix === 0xfe ? 4 : 8
+    if (offset + 1 + width > buf.length) {
+      throw new RangeError('compactSize: unexpected end of buffer')
+    }
+    const value = uncheckedConverter.arrayToLittleEndian(buf.subarray(offset + 1, offset + 1 + width))
+    const min = width === 2 ? 0xfdn : width === 4 ? 0x10000n : 0x100000000n
+    if (value < min) {
+      throw new RangeError(`compactSize: non canonical encoding of ${value}`)
+    }
+    return { value, bytesRead: width + 1 }
+  }
+}
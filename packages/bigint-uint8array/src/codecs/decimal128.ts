@@ -0,0 +1,89 @@
+import { uncheckedConverter } from '../converter/index.js'
+
+const BYTES = 16
+const EXPONENT_BIAS = 6176
+const MAX_EXPONENT = 6111
+const MAX_COEFFICIENT = 10n ** 34n - 1n
+// the largest finite value, MAX_COEFFICIENT * 10 ** MAX_EXPONENT, is 5104 hex digits long
+const MAX_MAGNITUDE_HEX_LENGTH = 5104
+
+/**
+ * IEEE 754-2008 decimal128 codec using the binary integer decimal (BID) encoding, stored little-endian
+ * as in the BSON Decimal128 type. Only integer values can be converted, inexact conversions are rejected.
+ */
+export const decimal128 = {
+  /**
+   * Encodes an integer as a decimal128.
+   * Values with more than 34 significant digits are only accepted when their trailing digits are zeros,
+   * which can be moved to the exponent without losing precision.
+   * @param num - number to encode
+   * @returns Uint8Array of 16 bytes, little-endian
+   * @throws RangeError if num cannot be represented exactly
+   * @example
+   * ```ts
+   * decimal128.encode(1n) // Uint8Array [ 0x01, 0x00, ..., 0x40, 0x30 ]
+   * decimal128.encode(10n ** 40n) // coefficient 10^33, exponent 7
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    let coefficient = num < 0n ? -num : num
+    if (coefficient.toString(16).length > MAX_MAGNITUDE_HEX_LENGTH) {
+      throw new RangeError('decimal128: value overflows the exponent range')
+    }
+    let exponent = 0
+    while (coefficient > MAX_COEFFICIENT && coefficient % 10n === 0n) {
+      coefficient /= 10n
+      if (++exponent > MAX_EXPONENT) {
+        throw new RangeError(`decimal128: ${num} overflows the exponent range`)
+      }
+    }
+    if (coefficient > MAX_COEFFICIENT) {
+      throw new RangeError(`decimal128: ${num} cannot be represented exactly with 34 digits`)
+    }
+    const bits = (num < 0n ? 1n << 127n : 0n) | (BigInt(exponent + EXPONENT_BIAS) << 113n) | coefficient
+    return uncheckedConverter.littleEndianToNewArray(bits, BYTES)
+  },
+
+  /**
+   * Decodes a decimal128 holding an integer value
+   * @param buf - buffer to read from
+   * @param offset - position of the value, defaults to 0
+   * @returns the decoded integer
+   * @throws RangeError if the buffer is too short, the value is NaN/Infinity or it has a fractional part
+   */
+  decode (buf: Uint8Array, offset = 0): bigint {
+    if (offset < 0 || offset + BYTES > buf.length) {
+      throw new RangeError('decimal128: unexpected end of buffer')
+    }
+    const bits = uncheckedConverter.arrayToLittleEndian(buf.subarray(offset, offset + BYTES))
+    const negative = (bits >> 127n) === 1n
+    const combination = Number((bits >> 122n) & 0x1fn)
+    if (combination >= 0b11110) {
+      throw new RangeError(`decimal128: ${combination === 0b11110 ? 'Infinity' : 'NaN'} is not an integer`)
+    }
+    let exponent: number
+    let coefficient: bigint
+    if ((combination >> 3) === 0b11) {
+      // the implicit 0b100 prefix makes the coefficient exceed 10^34 - 1, which is non canonical and read as zero
+      exponent = Number((bits >> 111n) & 0x3fffn) - EXPONENT_BIAS
+      coefficient = 0n
+    } else {
+      exponent = Number((bits >> 113n) & 0x3fffn) - EXPONENT_BIAS
+      coefficient = bits & ((1n << 113n) - 1n)
+      if (coefficient > MAX_COEFFICIENT) {
+        coefficient = 0n
+      }
+    }
+    let value: bigint
+    if (exponent >= 0) {
+      value = coefficient * 10n ** BigInt(exponent)
+    } else {
+      const divisor = 10n ** BigInt(-exponent)
+      if (coefficient % divisor !== 0n) {
+        throw new RangeError('decimal128: value has a fractional part')
+      }
+      value = coefficient / divisor
+    }
+    return negative ? -value : value
+  }
+}
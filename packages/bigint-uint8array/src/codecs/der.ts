@@ -0,0 +1,89 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { byteLength, signedByteLength } from '../utils.js'
+
+const INTEGER_TAG = 0x02
+
+/**
+ * ASN.1 DER codec for the INTEGER type: tag 0x02, definite length, minimal two's complement big-endian content.
+ */
+export const der = {
+  /**
+   * Encodes a number as a DER INTEGER (tag, length and content)
+   * @param num - number to encode, negative values are supported
+   * @returns Uint8Array
+   * @example
+   * ```ts
+   * der.encodeInteger(127n) // Uint8Array [ 0x02, 0x01, 0x7f ]
+   * der.encodeInteger(128n) // Uint8Array [ 0x02, 0x02, 0x00, 0x80 ]
+   * der.encodeInteger(-129n) // Uint8Array [ 0x02, 0x02, 0xff, 0x7f ]
+   * ```
+   */
+  encodeInteger (num: bigint): Uint8Array {
+    const contentLength = signedByteLength(num)
+    const length = encodeLength(contentLength)
+    const toRet = new Uint8Array(1 + length.length + contentLength)
+    toRet[0] = INTEGER_TAG
+    toRet.set(length, 1)
+    uncheckedConverter.bigEndianToArray(BigInt.asUintN(contentLength * 8, num), toRet.subarray(1 + length.length))
+    return toRet
+  },
+
+  /**
+   * Decodes a DER INTEGER, enforcing the DER minimal length and minimal content rules
+   * @param buf - buffer to read from
+   * @param offset - position of the tag byte, defaults to 0
+   * @returns the decoded value and the number of bytes the whole element spans
+   * @throws RangeError if the element is truncated, is not an INTEGER or is not DER encoded
+   */
+  decodeInteger (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset + 2 > buf.length) {
+      throw new RangeError('der: unexpected end of buffer')
+    }
+    if (buf[offset] !== INTEGER_TAG) {
+      throw new RangeError(`der: expected INTEGER tag 0x02, got 0x${buf[offset].toString(16)}`)
+    }
+    let start = offset + 2
+    let length = buf[offset + 1]
+    if (length === 0x80) {
+      throw new RangeError('der: indefinite length is not allowed')
+    }
+    if (length > 0x80) {
+      const lengthOfLength = length & 0x7f
+      if (start + lengthOfLength > buf.length) {
+        throw new RangeError('der: unexpected end of buffer')
+      }
+      if (buf[start] === 0) {
+        throw new RangeError('der: length has leading zeros')
+      }
+      length = Number(uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + lengthOfLength)))
+      if (length < 0x80) {
+        throw new RangeError('der: long form used for a short length')
+      }
+      start += lengthOfLength
+    }
+    if (length === 0) {
+      throw new RangeError('der: INTEGER content cannot be empty')
+    }
+    if (start + length > buf.length) {
+      throw new RangeError('der: unexpected end of buffer')
+    }
+    if (length > 1 && ((buf[start] === 0x00 && buf[start + 1] < 0x80) || (buf[start] === 0xff && buf[start + 1] >= 0x80))) {
+      throw new RangeError('der: INTEGER content is not minimal')
+    }
+    const content = buf.subarray(start, start + length)
+    const value = BigInt.asIntN(length * 8, uncheckedConverter.arrayToBigEndian(content))
+    return { value, bytesRead: start + length - offset }
+  }
+}
+
+function encodeLength (length: number): Uint8Array {
+  if (length < 0x80) {
+    return Uint8Array.of(length)
+  }
+  const bytes = byteLength(BigInt(length))
+  const toRet = new Uint8Array(1 + bytes)
+  toRet[0] = 0x80 | bytes
+  uncheckedConverter.bigEndianToArray(BigInt(length), toRet.subarray(1))
+  return toRet
+}
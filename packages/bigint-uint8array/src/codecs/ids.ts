@@ -0,0 +1,212 @@
+const CROCKFORD = '0123456789ABCDEFGHJKMNPQRSTVWXYZ'
+const ULID_REGEX = /^[0-7][0-9A-HJKMNP-TV-Z]{25}$/i
+
+/**
+ * Twitter's snowflake epoch, 2010-11-04T01:42:54.657Z
+ */
+export const SNOWFLAKE_TWITTER_EPOCH = 1288834974657
+
+/**
+ * Components of a {@link snowflake} id
+ */
+export interface SnowflakeComponents {
+  /**
+   * Unix time in milliseconds
+   */
+  timestamp: number
+  /**
+   * Machine (datacenter + worker) id, 10 bits
+   */
+  workerId: number
+  /**
+   * Per millisecond sequence, 12 bits
+   */
+  sequence: number
+}
+
+/**
+ * Components of a {@link ulid}
+ */
+export interface UlidComponents {
+  /**
+   * Unix time in milliseconds, 48 bits
+   */
+  timestamp: number
+  /**
+   * Random part, 80 bits
+   */
+  randomness: bigint
+}
+
+/**
+ * Components of a {@link uuidv7}
+ */
+export interface UuidV7Components {
+  /**
+   * Unix time in milliseconds, 48 bits
+   */
+  timestamp: number
+  /**
+   * `rand_a` field, 12 bits
+   */
+  randA: number
+  /**
+   * `rand_b` field, 62 bits
+   */
+  randB: bigint
+}
+
+/**
+ * 64-bit Snowflake ids: 41 bits of milliseconds since a custom epoch, 10 bits of worker id and 12 bits of sequence.
+ */
+export const snowflake = {
+  /**
+   * Splits a snowflake id into its components
+   * @param id - the snowflake id
+   * @param epoch - the epoch in unix milliseconds, defaults to {@link SNOWFLAKE_TWITTER_EPOCH}
+   * @returns the components
+   * @throws RangeError if id is not a positive 63-bit value
+   * @example
+   * ```ts
+   * snowflake.decode(1541815603606036480n) // { timestamp: 1656432460105, workerId: 378, sequence: 0 }
+   * ```
+   */
+  decode (id: bigint, epoch = SNOWFLAKE_TWITTER_EPOCH): SnowflakeComponents {
+    assertField('snowflake', 'id', id, 63)
+    return {
+      timestamp: Number(id >> 22n) + epoch,
+      workerId: Number((id >> 12n) & 0x3ffn),
+      sequence: Number(id & 0xfffn)
+    }
+  },
+
+  /**
+   * Builds a snowflake id from its components
+   * @param components - the components
+   * @param epoch - the epoch in unix milliseconds, defaults to {@link SNOWFLAKE_TWITTER_EPOCH}
+   * @returns the snowflake id
+   * @throws RangeError if a component does not fit its field or timestamp is before epoch
+   */
+  encode (components: SnowflakeComponents, epoch = SNOWFLAKE_TWITTER_EPOCH): bigint {
+    const timestamp = assertField('snowflake', 'timestamp', BigInt(components.timestamp - epoch), 41)
+    const workerId = assertField('snowflake', 'workerId', BigInt(components.workerId), 10)
+    const sequence = assertField('snowflake', 'sequence', BigInt(components.sequence), 12)
+    return (timestamp << 22n) | (workerId << 12n) | sequence
+  }
+}
+
+/**
+ * 128-bit ULIDs: 48 bits of unix milliseconds followed by 80 random bits, textually encoded in Crockford base32.
+ */
+export const ulid = {
+  /**
+   * Parses the 26 characters Crockford base32 representation, case insensitive
+   * @param str - the ULID string
+   * @returns the 128-bit value
+   * @throws SyntaxError if str is not a valid ULID
+   */
+  parse (str: string): bigint {
+    if (!ULID_REGEX.test(str)) {
+      throw new SyntaxError(`ulid: invalid ULID string ${JSON.stringify(str)}`)
+    }
+    let toRet = 0n
+    for (const char of str.toUpperCase()) {
+      toRet = (toRet << 5n) | BigInt(CROCKFORD.indexOf(char))
+    }
+    return toRet
+  },
+
+  /**
+   * Formats a 128-bit value as a 26 characters Crockford base32 string
+   * @param value - the 128-bit value
+   * @returns the ULID string
+   * @throws RangeError if value does not fit in 128 bits
+   */
+  format (value: bigint): string {
+    assertField('ulid', 'value', value, 128)
+    let toRet = ''
+    for (let shift = 125n; shift >= 0n; shift -= 5n) {
+      toRet += CROCKFORD[Number((value >> shift) & 0x1fn)]
+    }
+    return toRet
+  },
+
+  /**
+   * Splits a ULID into its components
+   * @param value - the 128-bit value
+   * @returns the components
+   * @throws RangeError if value does not fit in 128 bits
+   * @example
+   * ```ts
+   * ulid.decode(ulid.parse('01ARZ3NDEKTSV4RRFFQ69G5FAV')).timestamp // 1469922850259
+   * ```
+   */
+  decode (value: bigint): UlidComponents {
+    assertField('ulid', 'value', value, 128)
+    return {
+      timestamp: Number(value >> 80n),
+      randomness: BigInt.asUintN(80, value)
+    }
+  },
+
+  /**
+   * Builds a ULID from its components
+   * @param components - the components
+   * @returns the 128-bit value
+   * @throws RangeError if a component does not fit its field
+   */
+  encode (components: UlidComponents): bigint {
+    const timestamp = assertField('ulid', 'timestamp', BigInt(components.timestamp), 48)
+    const randomness = assertField('ulid', 'randomness', components.randomness, 80)
+    return (timestamp << 80n) | randomness
+  }
+}
+
+/**
+ * 128-bit UUIDv7 (RFC 9562): 48 bits of unix milliseconds, version, 12 bits `rand_a`, variant and 62 bits `rand_b`.
+ * Combine with {@link uuid} to go from and to strings or bytes.
+ */
+export const uuidv7 = {
+  /**
+   * Splits a UUIDv7 into its components
+   * @param value - the 128-bit value
+   * @returns the components
+   * @throws RangeError if value does not fit in 128 bits or is not a version 7, RFC 9562 variant UUID
+   * @example
+   * ```ts
+   * uuidv7.decode(uuid.parse('017f22e2-79b0-7cc3-98c4-dc0c0c07398f'))
+   * // { timestamp: 1645557742000, randA: 0xcc3, randB: 0x18c4dc0c0c07398fn }
+   * ```
+   */
+  decode (value: bigint): UuidV7Components {
+    assertField('uuidv7', 'value', value, 128)
+    if (((value >> 76n) & 0xfn) !== 7n || ((value >> 62n) & 0x3n) !== 0b10n) {
+      throw new RangeError('uuidv7: not a version 7 UUID')
+    }
+    return {
+      timestamp: Number(value >> 80n),
+      randA: Number((value >> 64n) & 0xfffn),
+      randB: BigInt.asUintN(62, value)
+    }
+  },
+
+  /**
+   * Builds a UUIDv7 from its components, setting the version and variant bits
+   * @param components - the components
+   * @returns the 128-bit value
+   * @throws RangeError if a component does not fit its field
+   */
+  encode (components: UuidV7Components): bigint {
+    const timestamp = assertField('uuidv7', 'timestamp', BigInt(components.timestamp), 48)
+    const randA = assertField('uuidv7', 'randA', BigInt(components.randA), 12)
+    const randB = assertField('uuidv7', 'randB', components.randB, 62)
+    return (timestamp << 80n) | (0x7n << 76n) | (randA << 64n) | (0b10n << 62n) | randB
+  }
+}
+
+function assertField (prefix: string, name: string, value: bigint, bits: number): bigint {
+  if (value < 0n || value >> BigInt(bits) !== 0n) {
+    throw new RangeError(`${prefix}: ${name} ${value} does not fit in ${bits} bits`)
+  }
+  return value
+}
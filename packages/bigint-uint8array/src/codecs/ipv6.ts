@@ -0,0 +1,131 @@
+const IPV6_MAX = (1n << 128n) - 1n
+const HEXTET_REGEX = /^[0-9a-f]{1,4}$/i
+const IPV4_REGEX = /^(25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)(\.(25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)){3}$/
+
+/**
+ * IPv6 addresses as 128-bit values, with the CIDR helpers needed for address arithmetic.
+ */
+export const ipv6 = {
+  /**
+   * Parses an IPv6 address in any RFC 4291 textual form, including `::` compression and
+   * a trailing dotted IPv4 part. Zone identifiers (`%eth0`) are not accepted.
+   * @param str - the address
+   * @returns the 128-bit value
+   * @throws SyntaxError if str is not a valid IPv6 address
+   * @example
+   * ```ts
+   * ipv6.parse('2001:db8::1') // 0x20010db8000000000000000000000001n
+   * ipv6.parse('::ffff:192.0.2.1') // 0xffffc0000201n
+   * ```
+   */
+  parse (str: string): bigint {
+    const invalid = (): SyntaxError => new SyntaxError(`ipv6: invalid address ${JSON.stringify(str)}`)
+    const halves = str.split('::')
+    if (halves.length > 2) {
+      throw invalid()
+    }
+    const groups = halves.map((half) => half === '' ? [] : half.split(':'))
+    const hextets: bigint[][] = groups.map(() => [])
+    for (let i = 0; i < groups.length; i++) {
+      for (let j = 0; j < groups[i].length; j++) {
+        const group = groups[i][j]
+        if (i === groups.length - 1 && j === groups[i].length - 1 && IPV4_REGEX.test(group)) {
+          const [a, b, c, d] = group.split('.').map(BigInt)
+          hextets[i].push((a << 8n) | b, (c << 8n) | d)
+        } else if (HEXTET_REGEX.test(group)) {
+          hextets[i].push(BigInt('0x' + group))
+        } else {
+          throw invalid()
+        }
+      }
+    }
+    const count = hextets.reduce((acc, h) => acc + h.length, 0)
+    if (halves.length === 1 ? count !== 8 : count > 7) {
+      throw invalid()
+    }
+    const all = halves.length === 1
+      ? hextets[0]
+      : [...hextets[0], ...new Array<bigint>(8 - count).fill(0n), ...hextets[1]]
+    return all.reduce((acc, h) => (acc << 16n) | h, 0n)
+  },
+
+  /**
+   * Formats a 128-bit value using the RFC 5952 canonical text representation
+   * (lowercase, no leading zeros, longest run of two or more zero groups compressed to `::`)
+   * @param value - the 128-bit value
+   * @returns the address string
+   * @throws RangeError if value does not fit in 128 bits
+   * @example
+   * ```ts
+   * ipv6.format(0x20010db8000000000000000000000001n) // '2001:db8::1'
+   * ```
+   */
+  format (value: bigint): string {
+    assertAddress(value)
+    const hextets: string[] = []
+    for (let i = 7; i >= 0; i--) {
+      hextets.push(((value >> BigInt(i * 16)) & 0xffffn).toString(16))
+    }
+    let bestStart = -1
+    let bestLength = 1
+    for (let i = 0; i < 8; i++) {
+      let j = i
+      while (j < 8 && hextets[j] === '0') {
+        j++
+      }
+      if (j - i > bestLength) {
+        bestStart = i
+        bestLength = j - i
+      }
+    }
+    if (bestStart === -1) {
+      return hextets.join(':')
+    }
+    return hextets.slice(0, bestStart).join(':') + '::' + hextets.slice(bestStart + bestLength).join(':')
+  },
+
+  /**
+   * Computes the first and last address of the network an address belongs to
+   * @param address - any address of the network
+   * @param prefix - the prefix length, from 0 to 128
+   * @returns the lowest (network) and highest address of the range
+   * @throws RangeError if address does not fit in 128 bits or prefix is not valid
+   * @example
+   * ```ts
+   * ipv6.networkRange(ipv6.parse('2001:db8::1'), 32) // { first: 2001:db8::, last: 2001:db8:ffff:ffff:ffff:ffff:ffff:ffff }
+   * ```
+   */
+  networkRange (address: bigint, prefix: number): { first: bigint, last: bigint } {
+    assertAddress(address)
+    const hostMask = hostMaskOf(prefix)
+    return { first: address & (IPV6_MAX ^ hostMask), last: address | hostMask }
+  },
+
+  /**
+   * Checks whether an address belongs to a network
+   * @param network - any address of the network
+   * @param prefix - the prefix length of the network, from 0 to 128
+   * @param address - the address to check
+   * @returns true if address is within network/prefix
+   * @throws RangeError if an address does not fit in 128 bits or prefix is not valid
+   */
+  contains (network: bigint, prefix: number, address: bigint): boolean {
+    assertAddress(network)
+    assertAddress(address)
+    const hostMask = hostMaskOf(prefix)
+    return (network | hostMask) === (address | hostMask)
+  }
+}
+
+function assertAddress (value: bigint): void {
+  if (value < 0n || value > IPV6_MAX) {
+    throw new RangeError(`ipv6: ${value} does not fit in 128 bits`)
+  }
+}
+
+function hostMaskOf (prefix: number): bigint {
+  if (!Number.isInteger(prefix) || prefix < 0 || prefix > 128) {
+    throw new RangeError(`ipv6: invalid prefix length ${prefix}`)
+  }
+  return (1n << BigInt(128 - prefix)) - 1n
+}
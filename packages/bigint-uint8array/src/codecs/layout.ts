@@ -0,0 +1,158 @@
+import { uncheckedConverter } from '../converter/index.js'
+import { assertIntBoundaries, assertUIntBoundaries } from '../utils.js'
+
+/**
+ * A fixed-width integer field of a {@link Layout}
+ */
+export interface IntegerField {
+  /**
+   * Width of the field in bytes
+   */
+  bytes: number
+  /**
+   * Byte order, defaults to `'be'`
+   */
+  endian?: 'be' | 'le'
+  /**
+   * Whether the field holds a two's complement signed integer, defaults to `false`
+   */
+  signed?: boolean
+}
+
+/**
+ * Unused bytes in a {@link Layout}: written as zeros and skipped when reading
+ */
+export interface PaddingField {
+  /**
+   * Number of padding bytes
+   */
+  padding: number
+}
+
+export type LayoutField = IntegerField | PaddingField
+
+/**
+ * A binary record made of consecutive fixed-width integer fields, created with {@link createLayout}.
+ * Values are passed and returned in field order, padding fields excluded.
+ */
+export interface Layout {
+  /**
+   * Total size of the record in bytes
+   */
+  readonly size: number
+  /**
+   * Packs the values into a new Uint8Array
+   * @param values - one value per integer field
+   * @throws RangeError if the number of values does not match the layout
+   * @throws Error if a value does not fit its field
+   */
+  pack(values: bigint[]): Uint8Array
+  /**
+   * Packs the values into an existing buffer
+   * @param values - one value per integer field
+   * @param dest - destination buffer
+   * @param offset - position of the record in dest, defaults to 0
+   * @throws RangeError if the number of values does not match the layout or dest is too small
+   * @throws Error if a value does not fit its field
+   */
+  packInto(values: bigint[], dest: Uint8Array, offset?: number): void
+  /**
+   * Reads the values of a record
+   * @param buf - buffer to read from
+   * @param offset - position of the record in buf, defaults to 0
+   * @returns one value per integer field
+   * @throws RangeError if buf is too small
+   */
+  unpack(buf: Uint8Array, offset?: number): bigint[]
+}
+
+/**
+ * Creates a {@link Layout} from a list of fields
+ * @param fields - the fields of the record, in order
+ * @returns Layout
+ * @throws RangeError if a field width is not a positive integer or a padding is negative
+ * @example
+ * ```ts
+ * const header = createLayout([
+ *   { bytes: 8, endian: 'le' },
+ *   { padding: 8 },
+ *   { bytes: 32, signed: true }
+ * ])
+ * const buf = header.pack([1n, -1n]) // 48 bytes
+ * header.unpack(buf) // [ 1n, -1n ]
+ * ```
+ */
+export function createLayout (fields: LayoutField[]): Layout {
+  let size = 0
+  for (const field of fields) {
+    const width = 'padding' in field ? field.padding : field.bytes
+    if (!Number.isInteger(width) || width < 0 || (!('padding' in field) && width === 0)) {
+      throw new RangeError(`layout: invalid field width ${width}`)
+    }
+    size += width
+  }
+  const integerFields = fields.filter((field): field is IntegerField => !('padding' in field))
+
+  const assertBuffer = (buf: Uint8Array, offset: number): void => {
+    if (offset < 0 || offset + size > buf.length) {
+      throw new RangeError(`layout: buffer too small, ${size} bytes needed at offset ${offset}`)
+    }
+  }
+
+  const layout: Layout = {
+    size,
+    pack (values: bigint[]): Uint8Array {
+      const toRet = new Uint8Array(size)
+      layout.packInto(values, toRet)
+      return toRet
+    },
+    packInto (values: bigint[], dest: Uint8Array, offset = 0): void {
+      if (values.length !== integerFields.length) {
+        throw new RangeError(`layout: expected ${integerFields.length} values, got ${values.length}`)
+      }
+      assertBuffer(dest, offset)
+      let position = offset
+      let index = 0
+      for (const field of fields) {
+        if ('padding' in field) {
+          dest.fill(0, position, position + field.padding)
+          position += field.padding
+          continue
+        }
+        let value = values[index++]
+        if (field.signed === true) {
+          assertIntBoundaries(value, field.bytes)
+          value = BigInt.asUintN(field.bytes * 8, value)
+        } else {
+          assertUIntBoundaries(value, field.bytes)
+        }
+        const slot = dest.subarray(position, position + field.bytes)
+        if (field.endian === 'le') {
+          uncheckedConverter.littleEndianToArray(value, slot)
+        } else {
+          uncheckedConverter.bigEndianToArray(value, slot)
+        }
+        position += field.bytes
+      }
+    },
+    unpack (buf: Uint8Array, offset = 0): bigint[] {
+      assertBuffer(buf, offset)
+      const toRet: bigint[] = []
+      let position = offset
+      for (const field of fields) {
+        if ('padding' in field) {
+          position += field.padding
+          continue
+        }
+        const slot = buf.subarray(position, position + field.bytes)
+        const value = field.endian === 'le'
+          ? uncheckedConverter.arrayToLittleEndian(slot)
+          : uncheckedConverter.arrayToBigEndian(slot)
+        toRet.push(field.signed === true ? BigInt.asIntN(field.bytes * 8, value) : value)
+        position += field.bytes
+      }
+      return toRet
+    }
+  }
+  return layout
+}
@@ -0,0 +1,98 @@
+import { type DecodeResult } from './types.js'
+
+/**
+ * Arbitrary precision LEB128 codec, as used by WebAssembly and DWARF.
+ */
+export const leb128 = {
+  /**
+   * Encodes a non-negative number as unsigned LEB128
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @throws RangeError if num is negative
+   * @example
+   * ```ts
+   * leb128.encodeUnsigned(624485n) // Uint8Array [ 0xe5, 0x8e, 0x26 ]
+   * ```
+   */
+  encodeUnsigned (num: bigint): Uint8Array {
+    if (num < 0n) {
+      throw new RangeError(`leb128: cannot encode negative ${num} as unsigned`)
+    }
+    const bytes: number[] = []
+    do {
+      let byte = Number(num & 0x7fn)
+      num >>= 7n
+      if (num !== 0n) {
+        byte |= 0x80
+      }
+      bytes.push(byte)
+    } while (num !== 0n)
+    return Uint8Array.from(bytes)
+  },
+
+  /**
+   * Decodes an unsigned LEB128 number
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the number, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer ends before the number does
+   */
+  decodeUnsigned (buf: Uint8Array, offset = 0): DecodeResult {
+    let value = 0n
+    let shift = 0n
+    for (let i = offset; i < buf.length; i++) {
+      value |= BigInt(buf[i] & 0x7f) << shift
+      shift += 7n
+      if ((buf[i] & 0x80) === 0) {
+        return { value, bytesRead: i - offset + 1 }
+      }
+    }
+    throw new RangeError('leb128: unexpected end of buffer')
+  },
+
+  /**
+   * Encodes a number as signed LEB128
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @example
+   * ```ts
+   * leb128.encodeSigned(-123456n) // Uint8Array [ 0xc0, 0xbb, 0x78 ]
+   * ```
+   */
+  encodeSigned (num: bigint): Uint8Array {
+    const bytes: number[] = []
+    for (;;) {
+      const byte = Number(num & 0x7fn)
+      num >>= 7n
+      const signBit = (byte & 0x40) !== 0
+      if ((num === 0n && !signBit) || (num === -1n && signBit)) {
+        bytes.push(byte)
+        return Uint8Array.from(bytes)
+      }
+      bytes.push(byte | 0x80)
+    }
+  },
+
+  /**
+   * Decodes a signed LEB128 number
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the number, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer ends before the number does
+   */
+  decodeSigned (buf: Uint8Array, offset = 0): DecodeResult {
+    let value = 0n
+    let shift = 0n
+    for (let i = offset; i < buf.length; i++) {
+      value |= BigInt(buf[i] & 0x7f) << shift
+      shift += 7n
+      if ((buf[i] & 0x80) === 0) {
+        if ((buf[i] & 0x40) !== 0) {
+          value -= 1n << shift
+        }
+        return { value, bytesRead: i - offset + 1 }
+      }
+    }
+    throw new RangeError('leb128: unexpected end of buffer')
+  }
+}
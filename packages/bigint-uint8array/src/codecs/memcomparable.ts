@@ -0,0 +1,107 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { byteLength } from '../utils.js'
+
+const ZERO = 0x80
+const MAX_SHORT_LENGTH = 126
+const LONG_LENGTH_BYTES = 4
+
+/**
+ * Order preserving ("memcomparable") codec for signed integers: comparing two encodings byte by byte
+ * gives the same result as comparing the numbers, so they can be used directly as keys in sorted
+ * key/value stores (RocksDB, LMDB, ...). Encodings are self delimiting and can be concatenated into composite keys.
+ *
+ * Layout of the first byte:
+ * - `0x80` zero
+ * - `0x81`..`0xfe` positive, followed by the 1..126 byte big-endian magnitude
+ * - `0xff` positive, followed by a uint32 big-endian magnitude length and the magnitude
+ * - `0x7e`..`0x01` negative, followed by the 1..126 byte magnitude with all bits inverted
+ * - `0x00` negative, followed by the inverted uint32 magnitude length and the inverted magnitude
+ */
+export const memcomparable = {
+  /**
+   * Encodes a number so that the lexicographic order of the encodings matches the numeric order
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @example
+   * ```ts
+   * memcomparable.encode(0n) // Uint8Array [ 0x80 ]
+   * memcomparable.encode(258n) // Uint8Array [ 0x82, 0x01, 0x02 ]
+   * memcomparable.encode(-258n) // Uint8Array [ 0x7d, 0xfe, 0xfd ]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    if (num === 0n) {
+      return Uint8Array.of(ZERO)
+    }
+    const negative = num < 0n
+    const magnitude = negative ? -num : num
+    const length = byteLength(magnitude)
+    const headerLength = length > MAX_SHORT_LENGTH ? 1 + LONG_LENGTH_BYTES : 1
+    const toRet = new Uint8Array(headerLength + length)
+    if (headerLength === 1) {
+      toRet[0] = ZERO + length
+    } else {
+      toRet[0] = 0xff
+      new DataView(toRet.buffer).setUint32(1, length)
+    }
+    uncheckedConverter.bigEndianToArray(magnitude, toRet.subarray(headerLength))
+    if (negative) {
+      for (let i = 0; i < toRet.length; i++) {
+        toRet[i] = ~toRet[i] & 0xff
+      }
+    }
+    return toRet
+  },
+
+  /**
+   * Decodes a number encoded with {@link memcomparable.encode}
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short or the encoding is not canonical
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset >= buf.length) {
+      throw new RangeError('memcomparable: unexpected end of buffer')
+    }
+    if (buf[offset] === ZERO) {
+      return { value: 0n, bytesRead: 1 }
+    }
+    const negative = buf[offset] < ZERO
+    const invert = negative ? 0xff : 0x00
+    const header = buf[offset] ^ invert
+    let start = offset + 1
+    let length = header - ZERO
+    if (length === 0) {
+      throw new RangeError('memcomparable: non canonical encoding of zero')
+    }
+    if (header === 0xff) {
+      if (start + LONG_LENGTH_BYTES > buf.length) {
+        throw new RangeError('memcomparable: unexpected end of buffer')
+      }
+      length = 0
+      for (let i = 0; i < LONG_LENGTH_BYTES; i++) {
+        length = length * 256 + (buf[start + i] ^ invert)
+      }
+      if (length <= MAX_SHORT_LENGTH) {
+        throw new RangeError('memcomparable: long length form used for a short magnitude')
+      }
+      start += LONG_LENGTH_BYTES
+    }
+    if (start + length > buf.length) {
+      throw new RangeError('memcomparable: unexpected end of buffer')
+    }
+    if ((buf[start] ^ invert) === 0) {
+      throw new RangeError('memcomparable: magnitude has leading zeros')
+    }
+    const magnitude = buf.slice(start, start + length)
+    if (negative) {
+      for (let i = 0; i < magnitude.length; i++) {
+        magnitude[i] = ~magnitude[i] & 0xff
+      }
+    }
+    const value = uncheckedConverter.arrayToBigEndian(magnitude)
+    return { value: negative ? -value : value, bytesRead: start + length - offset }
+  }
+}
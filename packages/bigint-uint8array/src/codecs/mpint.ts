@@ -0,0 +1,116 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { signedByteLength } from '../utils.js'
+
+/**
+ * SSH `mpint` codec (RFC 4251): a uint32 big-endian byte count followed by the minimal two's complement
+ * big-endian representation of the value. Zero is encoded with no content bytes.
+ */
+export const mpint = {
+  /**
+   * Encodes a number as an SSH mpint
+   * @param num - number to encode, negative values are supported
+   * @returns Uint8Array
+   * @example
+   * ```ts
+   * mpint.encode(0x80n) // Uint8Array [ 0x00, 0x00, 0x00, 0x02, 0x00, 0x80 ]
+   * mpint.encode(-0x1234n) // Uint8Array [ 0x00, 0x00, 0x00, 0x02, 0xed, 0xcc ]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    const length = num === 0n ? 0 : signedByteLength(num)
+    const toRet = new Uint8Array(4 + length)
+    new DataView(toRet.buffer).setUint32(0, length)
+    if (length > 0) {
+      uncheckedConverter.bigEndianToArray(BigInt.asUintN(length * 8, num), toRet.subarray(4))
+    }
+    return toRet
+  },
+
+  /**
+   * Decodes an SSH mpint, rejecting unnecessary leading 0x00 or 0xff bytes
+   * @param buf - buffer to read from
+   * @param offset - position of the length field, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short or the content is not minimal
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset + 4 > buf.length) {
+      throw new RangeError('mpint: unexpected end of buffer')
+    }
+    const length = new DataView(buf.buffer, buf.byteOffset + offset, 4).getUint32(0)
+    const start = offset + 4
+    if (start + length > buf.length) {
+      throw new RangeError('mpint: unexpected end of buffer')
+    }
+    if (length === 0) {
+      return { value: 0n, bytesRead: 4 }
+    }
+    const content = buf.subarray(start, start + length)
+    const value = BigInt.asIntN(length * 8, uncheckedConverter.arrayToBigEndian(content))
+    if (value === 0n || signedByteLength(value) !== length) {
+      throw new RangeError('mpint: content has unnecessary leading bytes')
+    }
+    return { value, bytesRead: 4 + length }
+  }
+}
+
+/**
+ * OpenPGP MPI codec (RFC 4880 section 3.2): a uint16 big-endian bit count followed by the
+ * big-endian magnitude. Only non-negative values can be represented.
+ */
+export const openpgpMpi = {
+  /**
+   * Encodes a non-negative number as an OpenPGP MPI
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @throws RangeError if num is negative or longer than 65535 bits
+   * @example
+   * ```ts
+   * openpgpMpi.encode(511n) // Uint8Array [ 0x00, 0x09, 0x01, 0xff ]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    if (num < 0n) {
+      throw new RangeError(`openpgpMpi: cannot encode negative ${num}`)
+    }
+    const bits = num === 0n ? 0 : num.toString(2).length
+    if (bits > 0xffff) {
+      throw new RangeError(`openpgpMpi: ${bits} bits exceed the 65535 bits limit`)
+    }
+    const length = Math.ceil(bits / 8)
+    const toRet = new Uint8Array(2 + length)
+    new DataView(toRet.buffer).setUint16(0, bits)
+    if (length > 0) {
+      uncheckedConverter.bigEndianToArray(num, toRet.subarray(2))
+    }
+    return toRet
+  },
+
+  /**
+   * Decodes an OpenPGP MPI, requiring the bit count to match the value exactly
+   * @param buf - buffer to read from
+   * @param offset - position of the bit count, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short or the bit count does not match the value
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset + 2 > buf.length) {
+      throw new RangeError('openpgpMpi: unexpected end of buffer')
+    }
+    const bits = new DataView(buf.buffer, buf.byteOffset + offset, 2).getUint16(0)
+    const length = Math.ceil(bits / 8)
+    const start = offset + 2
+    if (start + length > buf.length) {
+      throw new RangeError('openpgpMpi: unexpected end of buffer')
+    }
+    if (length === 0) {
+      return { value: 0n, bytesRead: 2 }
+    }
+    const value = uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + length))
+    if (value.toString(2).length !== bits || value === 0n) {
+      throw new RangeError(`openpgpMpi: bit count ${bits} does not match the value`)
+    }
+    return { value, bytesRead: 2 + length }
+  }
+}
@@ -0,0 +1,93 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { signedByteLength } from '../utils.js'
+
+const FIXEXT: Record<number, number> = { 1: 0xd4, 2: 0xd5, 4: 0xd6, 8: 0xd7, 16: 0xd8 }
+const EXT8 = 0xc7
+const EXT16 = 0xc8
+const EXT32 = 0xc9
+
+/**
+ * MessagePack extension type codec for arbitrary precision integers.
+ * The payload is the minimal two's complement big-endian representation of the value,
+ * wrapped in the smallest `fixext`/`ext` format that fits it.
+ */
+export const msgpack = {
+  /**
+   * Encodes a number as a MessagePack extension
+   * @param num - number to encode, negative values are supported
+   * @param type - the application defined extension type id, between -128 and 127
+   * @returns Uint8Array
+   * @throws RangeError if type is not a signed 8 bit integer
+   * @example
+   * ```ts
+   * msgpack.encodeExt(1n, 1) // Uint8Array [ 0xd4, 0x01, 0x01 ]
+   * msgpack.encodeExt(-(2n ** 64n), 1) // Uint8Array [ 0xc7, 0x09, 0x01, 0xff, 0x00, ... ]
+   * ```
+   */
+  encodeExt (num: bigint, type: number): Uint8Array {
+    assertType(type)
+    const length = signedByteLength(num)
+    let header: number[]
+    if (FIXEXT[length] !== undefined) {
+      header = [FIXEXT[length]]
+    } else if (length <= 0xff) {
+      header = [EXT8, length]
+    } else if (length <= 0xffff) {
+      header = [EXT16, length >> 8, length & 0xff]
+    } else {
+      header = [EXT32, (length >>> 24) & 0xff, (length >> 16) & 0xff, (length >> 8) & 0xff, length & 0xff]
+    }
+    header.push(type & 0xff)
+    const toRet = new Uint8Array(header.length + length)
+    toRet.set(header)
+    uncheckedConverter.bigEndianToArray(BigInt.asUintN(length * 8, num), toRet.subarray(header.length))
+    return toRet
+  },
+
+  /**
+   * Decodes a MessagePack extension holding an integer
+   * @param buf - buffer to read from
+   * @param type - the expected extension type id, between -128 and 127
+   * @param offset - position of the format byte, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short, the item is not an extension of the given type or it is empty
+   */
+  decodeExt (buf: Uint8Array, type: number, offset = 0): DecodeResult {
+    assertType(type)
+    if (offset >= buf.length) {
+      throw new RangeError('msgpack: unexpected end of buffer')
+    }
+    const format = buf[offset]
+    const fixLength = Object.keys(FIXEXT).find((k) => FIXEXT[Number(k)] === format)
+    const lengthBytes = format === EXT8 ? 1 : format === EXT16 ? 2 : format === EXT32 ? 4 : 0
+    if (fixLength === undefined && lengthBytes === 0) {
+      throw new RangeError(`msgpack: expected an extension, got format 0x${format.toString(16)}`)
+    }
+    const start = offset + 2 + lengthBytes
+    if (start > buf.length) {
+      throw new RangeError('msgpack: unexpected end of buffer')
+    }
+    const length = fixLength !== undefined
+      ? Number(fixLength)
+      : Number(uncheckedConverter.arrayToBigEndian(buf.subarray(offset + 1, offset + 1 + lengthBytes)))
+    const actualType = (buf[start - 1] << 24) >> 24
+    if (actualType !== type) {
+      throw new RangeError(`msgpack: expected extension type ${type}, got ${actualType}`)
+    }
+    if (length === 0) {
+      throw new RangeError('msgpack: extension payload is empty')
+    }
+    if (start + length > buf.length) {
+      throw new RangeError('msgpack: unexpected end of buffer')
+    }
+    const value = BigInt.asIntN(length * 8, uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + length)))
+    return { value, bytesRead: start + length - offset }
+  }
+}
+
+function assertType (type: number): void {
+  if (!Number.isInteger(type) || type < -128 || type > 127) {
+    throw new RangeError(`msgpack: extension type ${type} is not a signed 8 bit integer`)
+  }
+}
@@ -0,0 +1,64 @@
+/**
+ * Bitcoin block header "bits" codec: the compact floating point representation of the proof of work target.
+ *
+ * The encoding is `size(1 byte) | mantissa(3 bytes)` where the top bit of the mantissa is a sign bit.
+ * Both directions reproduce Bitcoin Core's `SetCompact`/`GetCompact`, including negative values.
+ */
+export const nBits = {
+  /**
+   * Encodes a target in the compact representation. Precision beyond the 3 most significant bytes is dropped.
+   * @param target - the target, negative values set the sign bit
+   * @returns the compact bits as an unsigned 32 bit number
+   * @example
+   * ```ts
+   * nBits.targetToCompact(0xffffn << 208n) // 0x1d00ffff
+   * nBits.targetToCompact(-0x12345600n) // 0x04923456
+   * ```
+   */
+  targetToCompact (target: bigint): number {
+    const negative = target < 0n
+    const abs = negative ? -target : target
+    let size = Math.ceil(abs.toString(2).length / 8)
+    let mantissa = size <= 3 ? abs << BigInt(8 * (3 - size)) : abs >> BigInt(8 * (size - 3))
+    if (abs === 0n) {
+      size = 0
+      mantissa = 0n
+    }
+    if ((mantissa & 0x800000n) !== 0n) {
+      mantissa >>= 8n
+      size++
+    }
+    if (size > 0xff) {
+      throw new RangeError(`nBits: ${target} is too large to be represented`)
+    }
+    let compact = Number(mantissa) | (size << 24)
+    if (negative && mantissa !== 0n) {
+      compact |= 0x800000
+    }
+    return compact >>> 0
+  },
+
+  /**
+   * Decodes the compact representation into a target
+   * @param compact - the compact bits, an unsigned 32 bit number
+   * @returns the target, negative when the sign bit is set and the mantissa is not zero
+   * @throws RangeError if compact is not an unsigned 32 bit integer or the target would exceed 256 bits
+   * @example
+   * ```ts
+   * nBits.compactToTarget(0x1d00ffff) // 0xffffn << 208n
+   * nBits.compactToTarget(0x01fedcba) // -0x7en
+   * ```
+   */
+  compactToTarget (compact: number): bigint {
+    if (!Number.isInteger(compact) || compact < 0 || compact > 0xffffffff) {
+      throw new RangeError(`nBits: ${compact} is not an unsigned 32 bit integer`)
+    }
+    const size = compact >>> 24
+    const word = compact & 0x007fffff
+    if (word !== 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32))) {
+      throw new RangeError(`nBits: 0x${compact.toString(16)} overflows 256 bits`)
+    }
+    const target = size <= 3 ? BigInt(word >>> (8 * (3 - size))) : BigInt(word) << BigInt(8 * (size - 3))
+    return word !== 0 && (compact & 0x00800000) !== 0 ? -target : target
+  }
+}
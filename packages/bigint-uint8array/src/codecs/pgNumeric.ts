@@ -0,0 +1,107 @@
+import { type DecodeResult } from './types.js'
+
+const NBASE = 10000n
+const SIGN_POSITIVE = 0x0000
+const SIGN_NEGATIVE = 0x4000
+// NBASE ** 0x8000 is 108853 hex digits long
+const MAX_MAGNITUDE_HEX_LENGTH = 108853
+let cachedMaxMagnitude: bigint | undefined
+
+/**
+ * PostgreSQL NUMERIC binary wire format codec (as used by `COPY BINARY` and binary protocol parameters),
+ * restricted to integer values.
+ *
+ * Layout: int16 number of digits, int16 weight, uint16 sign, uint16 display scale, followed by the
+ * base 10000 digits (int16 each) from the most significant one.
+ */
+export const pgNumeric = {
+  /**
+   * Encodes an integer in the NUMERIC binary format
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @throws RangeError if num has more digits than NUMERIC supports
+   * @example
+   * ```ts
+   * pgNumeric.encode(123456789n) // ndigits=3 weight=2 sign=0 dscale=0 digits=[1, 2345, 6789]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    let magnitude = num < 0n ? -num : num
+    // weight (int16) caps the value below NBASE ** 0x8000, check before the per digit loop
+    const hexLength = magnitude.toString(16).length
+    if (hexLength > MAX_MAGNITUDE_HEX_LENGTH || (hexLength === MAX_MAGNITUDE_HEX_LENGTH && magnitude >= maxMagnitude())) {
+      throw new RangeError('pgNumeric: value exceeds the NUMERIC precision')
+    }
+    const digits: number[] = []
+    while (magnitude > 0n) {
+      digits.push(Number(magnitude % NBASE))
+      magnitude /= NBASE
+    }
+    digits.reverse()
+    const weight = digits.length - 1
+    while (digits.length > 0 && digits[digits.length - 1] === 0) {
+      digits.pop()
+    }
+    if (digits.length > 0x7fff) {
+      throw new RangeError('pgNumeric: value exceeds the NUMERIC precision')
+    }
+    const toRet = new Uint8Array(8 + digits.length * 2)
+    const view = new DataView(toRet.buffer)
+    view.setInt16(0, digits.length)
+    view.setInt16(2, Math.max(weight, 0))
+    view.setUint16(4, num < 0n ? SIGN_NEGATIVE : SIGN_POSITIVE)
+    view.setUint16(6, 0)
+    digits.forEach((digit, i) => view.setInt16(8 + i * 2, digit))
+    return toRet
+  },
+
+  /**
+   * Decodes a NUMERIC binary value holding an integer.
+   * A non zero display scale is accepted as long as the fractional digits are all zero.
+   * @param buf - buffer to read from
+   * @param offset - position of the header, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short, the value is NaN/Infinity, has a fractional part
+   * or contains an invalid digit
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset + 8 > buf.length) {
+      throw new RangeError('pgNumeric: unexpected end of buffer')
+    }
+    const view = new DataView(buf.buffer, buf.byteOffset + offset, buf.length - offset)
+    const ndigits = view.getInt16(0)
+    const weight = view.getInt16(2)
+    const sign = view.getUint16(4)
+    if (sign !== SIGN_POSITIVE && sign !== SIGN_NEGATIVE) {
+      throw new RangeError(`pgNumeric: special value 0x${sign.toString(16)} is not an integer`)
+    }
+    if (ndigits < 0 || 8 + ndigits * 2 > view.byteLength) {
+      throw new RangeError('pgNumeric: unexpected end of buffer')
+    }
+    let value = 0n
+    for (let i = 0; i < ndigits; i++) {
+      const digit = view.getInt16(8 + i * 2)
+      if (digit < 0 || digit >= Number(NBASE)) {
+        throw new RangeError(`pgNumeric: invalid base 10000 digit ${digit}`)
+      }
+      if (i > weight) {
+        if (digit !== 0) {
+          throw new RangeError('pgNumeric: value has a fractional part')
+        }
+        continue
+      }
+      value = value * NBASE + BigInt(digit)
+    }
+    if (weight >= ndigits) {
+      value *= NBASE ** BigInt(weight - ndigits + 1)
+    }
+    return { value: sign === SIGN_NEGATIVE ? -value : value, bytesRead: 8 + ndigits * 2 }
+  }
+}
+
+function maxMagnitude (): bigint {
+  if (cachedMaxMagnitude === undefined) {
+    cachedMaxMagnitude = NBASE ** 0x8000n
+  }
+  return cachedMaxMagnitude
+}
@@ -0,0 +1,79 @@
+import { type DecodeResult } from './types.js'
+import { leb128 } from './leb128.js'
+
+const MAX_VARINT_BYTES = 10
+const UINT64_MAX = 2n ** 64n - 1n
+const INT64_MIN = -(2n ** 63n)
+
+/**
+ * Protocol Buffers varint and ZigZag codec.
+ *
+ * Varints are limited to 64 bits as in the protobuf wire format: negative values are written as
+ * their 64-bit two's complement (`int64` semantics), while ZigZag works on arbitrary precision values.
+ */
+export const protobuf = {
+  /**
+   * Encodes a number as a protobuf varint
+   * @param num - number to encode, between -2^63 and 2^64 - 1
+   * @returns Uint8Array of 1 to 10 bytes
+   * @throws RangeError if num does not fit in 64 bits
+   * @example
+   * ```ts
+   * protobuf.encodeVarint(300n) // Uint8Array [ 0xac, 0x02 ]
+   * protobuf.encodeVarint(-1n) // Uint8Array [ 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01 ]
+   * ```
+   */
+  encodeVarint (num: bigint): Uint8Array {
+    if (num < INT64_MIN || num > UINT64_MAX) {
+      throw new RangeError(`protobuf: ${num} does not fit in a 64 bit varint`)
+    }
+    return leb128.encodeUnsigned(BigInt.asUintN(64, num))
+  },
+
+  /**
+   * Decodes a protobuf varint as an unsigned 64 bit value.
+   * Use `BigInt.asIntN(64, value)` to interpret `int64` fields.
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the varint, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer ends early, or the varint is longer than 10 bytes or exceeds 64 bits
+   */
+  decodeVarint (buf: Uint8Array, offset = 0): DecodeResult {
+    const window = buf.subarray(offset, offset + MAX_VARINT_BYTES)
+    if (window.length === MAX_VARINT_BYTES && window.every((byte) => (byte & 0x80) !== 0)) {
+      throw new RangeError(`protobuf: varint longer than ${MAX_VARINT_BYTES} bytes`)
+    }
+    const decoded = leb128.decodeUnsigned(window)
+    if (decoded.value > UINT64_MAX) {
+      throw new RangeError('protobuf: varint exceeds 64 bits')
+    }
+    return decoded
+  },
+
+  /**
+   * Maps a signed number to an unsigned one so that small magnitudes stay small (`sint32`/`sint64` encoding)
+   * @param num - signed number
+   * @returns the ZigZag encoded number
+   * @example
+   * ```ts
+   * protobuf.zigzagEncode(-1n) // 1n
+   * protobuf.zigzagEncode(1n) // 2n
+   * ```
+   */
+  zigzagEncode (num: bigint): bigint {
+    return num >= 0n ? num << 1n : (-num << 1n) - 1n
+  },
+
+  /**
+   * Inverse of {@link protobuf.zigzagEncode}
+   * @param num - ZigZag encoded number
+   * @returns the signed number
+   * @throws RangeError if num is negative
+   */
+  zigzagDecode (num: bigint): bigint {
+    if (num < 0n) {
+      throw new RangeError(`protobuf: ZigZag value ${num} cannot be negative`)
+    }
+    return (num & 1n) === 0n ? num >> 1n : -(num >> 1n) - 1n
+  }
+}
@@ -0,0 +1,93 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { byteLength } from '../utils.js'
+
+/**
+ * Ethereum RLP codec for unsigned integers: items holding the minimal big-endian representation of the value.
+ */
+export const rlp = {
+  /**
+   * Encodes a non-negative number as an RLP string item
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @throws RangeError if num is negative
+   * @example
+   * ```ts
+   * rlp.encodeUint(0n) // Uint8Array [ 0x80 ]
+   * rlp.encodeUint(15n) // Uint8Array [ 0x0f ]
+   * rlp.encodeUint(1024n) // Uint8Array [ 0x82, 0x04, 0x00 ]
+   * ```
+   */
+  encodeUint (num: bigint): Uint8Array {
+    if (num < 0n) {
+      throw new RangeError(`rlp: cannot encode negative ${num}`)
+    }
+    if (num === 0n) {
+      return Uint8Array.of(0x80)
+    }
+    if (num < 0x80n) {
+      return Uint8Array.of(Number(num))
+    }
+    const length = byteLength(num)
+    if (length <= 55) {
+      const toRet = new Uint8Array(1 + length)
+      toRet[0] = 0x80 + length
+      uncheckedConverter.bigEndianToArray(num, toRet.subarray(1))
+      return toRet
+    }
+    const lengthOfLength = byteLength(BigInt(length))
+    const toRet = new Uint8Array(1 + lengthOfLength + length)
+    toRet[0] = 0xb7 + lengthOfLength
+    uncheckedConverter.bigEndianToArray(BigInt(length), toRet.subarray(1, 1 + lengthOfLength))
+    uncheckedConverter.bigEndianToArray(num, toRet.subarray(1 + lengthOfLength))
+    return toRet
+  },
+
+  /**
+   * Decodes an RLP string item as an unsigned integer, rejecting anything that is not the canonical encoding
+   * (leading zeros, single bytes below 0x80 wrapped in a string, non minimal length prefixes, lists)
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the item, defaults to 0
+   * @returns the decoded value and the number of bytes the item spans
+   * @throws RangeError if the buffer is too short or the item is not a canonical integer
+   */
+  decodeUint (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset >= buf.length) {
+      throw new RangeError('rlp: unexpected end of buffer')
+    }
+    const prefix = buf[offset]
+    if (prefix < 0x80) {
+      return { value: BigInt(prefix), bytesRead: 1 }
+    }
+    if (prefix >= 0xc0) {
+      throw new RangeError('rlp: expected a string item, found a list')
+    }
+    let start = offset + 1
+    let length = prefix - 0x80
+    if (prefix > 0xb7) {
+      const lengthOfLength = prefix - 0xb7
+      if (start + lengthOfLength > buf.length) {
+        throw new RangeError('rlp: unexpected end of buffer')
+      }
+      if (buf[start] === 0) {
+        throw new RangeError('rlp: length prefix has leading zeros')
+      }
+      length = Number(uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + lengthOfLength)))
+      if (length <= 55) {
+        throw new RangeError('rlp: long length prefix used for a short string')
+      }
+      start += lengthOfLength
+    }
+    if (start + length > buf.length) {
+      throw new RangeError('rlp: unexpected end of buffer')
+    }
+    if (length > 0 && buf[start] === 0) {
+      throw new RangeError('rlp: integer has leading zeros')
+    }
+    if (length === 1 && buf[start] < 0x80) {
+      throw new RangeError('rlp: single byte below 0x80 must not be prefixed')
+    }
+    const value = uncheckedConverter.arrayToBigEndian(buf.subarray(start, start + length))
+    return { value, bytesRead: start + length - offset }
+  }
+}
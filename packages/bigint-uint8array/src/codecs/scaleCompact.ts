@@ -0,0 +1,74 @@
+import { type DecodeResult } from './types.js'
+import { uncheckedConverter } from '../converter/index.js'
+import { byteLength } from '../utils.js'
+
+const MAX_BIG_INTEGER_BYTES = 67
+
+/**
+ * SCALE compact integer codec (Substrate/Polkadot).
+ *
+ * The two least significant bits of the first byte select the mode:
+ * `0b00` single byte, `0b01` two bytes, `0b10` four bytes, `0b11` big-integer mode where the upper six bits
+ * hold the number of following little-endian bytes minus 4. Values up to 2^536 - 1 are supported.
+ */
+export const scaleCompact = {
+  /**
+   * Encodes a non-negative number in its canonical SCALE compact form
+   * @param num - number to encode
+   * @returns Uint8Array
+   * @throws RangeError if num is negative or larger than 2^536 - 1
+   * @example
+   * ```ts
+   * scaleCompact.encode(1n) // Uint8Array [ 0x04 ]
+   * scaleCompact.encode(69n) // Uint8Array [ 0x15, 0x01 ]
+   * scaleCompact.encode(1073741824n) // Uint8Array [ 0x03, 0x00, 0x00, 0x00, 0x40 ]
+   * ```
+   */
+  encode (num: bigint): Uint8Array {
+    if (num < 0n) {
+      throw new RangeError(`scaleCompact: cannot encode negative ${num}`)
+    }
+    if (num < 1n << 6n) {
+      return Uint8Array.of(Number(num << 2n))
+    }
+    if (num < 1n << 14n) {
+      return uncheckedConverter.littleEndianToNewArray((num << 2n) | 0b01n, 2)
+    }
+    if (num < 1n << 30n) {
+      return uncheckedConverter.littleEndianToNewArray((num << 2n) | 0b10n, 4)
+    }
+    const bytes = byteLength(num)
+    if (bytes > MAX_BIG_INTEGER_BYTES) {
+      throw new RangeError(`scaleCompact: ${num} does not fit in ${MAX_BIG_INTEGER_BYTES} bytes`)
+    }
+    const toRet = new Uint8Array(bytes + 1)
+    toRet[0] = ((bytes - 4) << 2) | 0b11
+    uncheckedConverter.littleEndianToArray(num, toRet.subarray(1))
+    return toRet
+  },
+
+  /**
+   * Decodes a SCALE compact number, rejecting non canonical encodings
+   * @param buf - buffer to read from
+   * @param offset - position of the first byte of the number, defaults to 0
+   * @returns the decoded value and the number of bytes it spans
+   * @throws RangeError if the buffer is too short or the value is not encoded in its shortest form
+   */
+  decode (buf: Uint8Array, offset = 0): DecodeResult {
+    if (offset >= buf.length) {
+      throw new RangeError('scaleCompact: unexpected end of buffer')
+    }
+    const mode = buf[offset] & 0b11
+    const width = mode === 0b00 ? 1 : mode === 0b01 ? 2 : mode === 0b10 ? 4 : (buf[offset] >> 2) + 5
+    if (offset + width > buf.length) {
+      throw new RangeError('scaleCompact: unexpected end of buffer')
+    }
+    const raw = uncheckedConverter.arrayToLittleEndian(buf.subarray(offset, offset + width))
+    const value = mode === 0b11 ? raw >> 8n : raw >> 2n
+    const min = mode === 0b00 ? 0n : mode === 0b01 ? 1n << 6n : mode === 0b10 ? 1n << 14n : 1n << 30n
+    if (value < min || (mode === 0b11 && byteLength(value) !== width - 1)) {
+      throw new RangeError(`scaleCompact: non canonical encoding of ${value}`)
+    }
+    return { value, bytesRead: width }
+  }
+}
@@ -0,0 +1,48 @@
+import { uncheckedConverter } from '../converter/index.js'
+
+const SSZ_UINT_BITS = [8, 16, 32, 64, 128, 256]
+
+/**
+ * SimpleSerialize (Ethereum consensus layer) codec for `uintN` basic types: fixed-width little-endian.
+ */
+export const ssz = {
+  /**
+   * Serializes a `uintN` value
+   * @param num - number to serialize
+   * @param bits - one of 8, 16, 32, 64, 128 or 256
+   * @returns Uint8Array of bits / 8 bytes
+   * @throws RangeError if bits is not a valid SSZ uint size or num does not fit
+   * @example
+   * ```ts
+   * ssz.encodeUint(1n, 64) // Uint8Array [ 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ]
+   * ```
+   */
+  encodeUint (num: bigint, bits: number): Uint8Array {
+    assertBits(bits)
+    if (num < 0n || num >= 1n << BigInt(bits)) {
+      throw new RangeError(`ssz: ${num} is out of boundaries for uint${bits}`)
+    }
+    return uncheckedConverter.littleEndianToNewArray(num, bits / 8)
+  },
+
+  /**
+   * Deserializes a `uintN` value. As mandated by SSZ the input must be exactly bits / 8 bytes long.
+   * @param buf - serialized value
+   * @param bits - one of 8, 16, 32, 64, 128 or 256
+   * @returns the deserialized value
+   * @throws RangeError if bits is not a valid SSZ uint size or buf has the wrong length
+   */
+  decodeUint (buf: Uint8Array, bits: number): bigint {
+    assertBits(bits)
+    if (buf.length !== bits / 8) {
+      throw new RangeError(`ssz: uint${bits} must be ${bits / 8} bytes long, got ${buf.length}`)
+    }
+    return uncheckedConverter.arrayToLittleEndian(buf)
+  }
+}
+
+function assertBits (bits: number): void {
+  if (!SSZ_UINT_BITS.includes(bits)) {
+    throw new RangeError(`ssz: invalid uint size ${bits}`)
+  }
+}
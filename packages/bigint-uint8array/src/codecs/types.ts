@@ -0,0 +1,13 @@
+/**
+ * Result of decoding a variable-length integer from a buffer
+ */
+export interface DecodeResult {
+  /**
+   * The decoded value
+   */
+  value: bigint
+  /**
+   * Number of bytes consumed from the buffer
+   */
+  bytesRead: number
+}
@@ -0,0 +1,109 @@
+import { uncheckedConverter } from '../converter/index.js'
+
+const UUID_MAX = (1n << 128n) - 1n
+const UUID_REGEX = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i
+
+/**
+ * Variant of a UUID as defined by RFC 9562, section 4.1
+ */
+export type UuidVariant = 'ncs' | 'rfc9562' | 'microsoft' | 'future'
+
+/**
+ * Conversions between UUIDs and their 128-bit integer value, handy to partition UUID keyspaces into ranges.
+ * Bytes are in network order (big-endian) as mandated by RFC 9562.
+ */
+export const uuid = {
+  /**
+   * Parses a UUID in its canonical `8-4-4-4-12` hex form, case insensitive
+   * @param str - the UUID string
+   * @returns the 128-bit value
+   * @throws SyntaxError if str is not a canonical UUID
+   * @example
+   * ```ts
+   * uuid.parse('00000000-0000-0000-0000-0000000000ff') // 255n
+   * ```
+   */
+  parse (str: string): bigint {
+    if (!UUID_REGEX.test(str)) {
+      throw new SyntaxError(`uuid: invalid UUID string ${JSON.stringify(str)}`)
+    }
+    return BigInt('0x' + str.replace(/-/g, ''))
+  },
+
+  /**
+   * Formats a 128-bit value as a lowercase canonical UUID string
+   * @param value - the 128-bit value
+   * @returns the UUID string
+   * @throws RangeError if value does not fit in 128 bits
+   */
+  format (value: bigint): string {
+    assertUuid(value)
+    const hex = value.toString(16).padStart(32, '0')
+    return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`
+  },
+
+  /**
+   * Converts a 128-bit value to the 16 bytes UUID binary form
+   * @param value - the 128-bit value
+   * @returns Uint8Array of 16 bytes
+   * @throws RangeError if value does not fit in 128 bits
+   */
+  toBytes (value: bigint): Uint8Array {
+    assertUuid(value)
+    return uncheckedConverter.bigEndianToNewArray(value, 16)
+  },
+
+  /**
+   * Reads a UUID from its 16 bytes binary form
+   * @param buf - buffer holding the UUID
+   * @param offset - position of the UUID in buf, defaults to 0
+   * @returns the 128-bit value
+   * @throws RangeError if fewer than 16 bytes are available
+   */
+  fromBytes (buf: Uint8Array, offset = 0): bigint {
+    if (offset < 0 || offset + 16 > buf.length) {
+      throw new RangeError('uuid: unexpected end of buffer')
+    }
+    return uncheckedConverter.arrayToBigEndian(buf.subarray(offset, offset + 16))
+  },
+
+  /**
+   * Extracts the version field. Only meaningful for the `rfc9562` variant.
+   * @param value - the 128-bit value
+   * @returns the version, from 0 to 15
+   * @throws RangeError if value does not fit in 128 bits
+   * @example
+   * ```ts
+   * uuid.version(uuid.parse('f81d4fae-7dec-11d0-a765-00a0c91e6bf6')) // 1
+   * ```
+   */
+  version (value: bigint): number {
+    assertUuid(value)
+    return Number((value >> 76n) & 0xfn)
+  },
+
+  /**
+   * Extracts the variant field
+   * @param value - the 128-bit value
+   * @returns the variant
+   * @throws RangeError if value does not fit in 128 bits
+   */
+  variant (value: bigint): UuidVariant {
+    assertUuid(value)
+    const bits = Number((value >> 61n) & 0x7n)
+    if (bits < 0b100) {
+      return 'ncs'
+    } else if (bits < 0b110) {
+      return 'rfc9562'
+    } else if (bits === 0b110) {
+      return 'microsoft'
+    }
+    return 'future'
+  }
+}
+
+function assertUuid (value: bigint): void {
+  if (value < 0n || value > UUID_MAX) {
+    throw new RangeError(`uuid: ${value} does not fit in 128 bits`)
+  }
+}
@@ -4,6 +4,28 @@ import {
 import { type UncheckedConverter } from './converter/type.js'
 import {assertIntBoundaries, assertUIntBoundaries} from "@/utils.js";
 export { type UncheckedConverter } from './converter/type.js'
+export { type DecodeResult } from './codecs/types.js'
+export * from './codecs/abi.js'
+export * from './codecs/bitfield.js'
+export * from './codecs/borsh.js'
+export * from './codecs/cbor.js'
+export * from './codecs/compactSize.js'
+export * from './codecs/decimal128.js'
+export * from './codecs/der.js'
+export * from './codecs/ids.js'
+export * from './codecs/ipv6.js'
+export * from './codecs/layout.js'
+export * from './codecs/leb128.js'
+export * from './codecs/memcomparable.js'
+export * from './codecs/mpint.js'
+export * from './codecs/msgpack.js'
+export * from './codecs/nBits.js'
+export * from './codecs/pgNumeric.js'
+export * from './codecs/protobuf.js'
+export * from './codecs/rlp.js'
+export * from './codecs/scaleCompact.js'
+export * from './codecs/ssz.js'
+export * from './codecs/uuid.js'
 
 /**
  * BigIntConverter is a set of functions to convert between BigInt and Uint8Array
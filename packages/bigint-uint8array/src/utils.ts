@@ -20,3 +20,22 @@ export function assertUIntBoundaries (n: bigint, nBytes: number): bigint {
   }
   return n
 }
+
+/**
+ * Minimum number of bytes needed to represent the magnitude of n (0 for 0n)
+ */
+export function byteLength (n: bigint): number {
+  if (n === 0n) {
+    return 0
+  }
+  return Math.ceil((n < 0n ? -n : n).toString(16).length / 2)
+}
+
+/**
+ * Minimum number of bytes needed to represent n in two's complement (at least 1)
+ */
+export function signedByteLength (n: bigint): number {
+  const magnitude = n < 0n ? -n - 1n : n
+  const bytes = Math.max(1, byteLength(magnitude))
+  return (magnitude >> BigInt(bytes * 8 - 1)) !== 0n ? bytes + 1 : bytes
+}
@@ -0,0 +1,48 @@
+import { describe, expect, it } from 'vitest'
+import { abi } from '@/index.js'
+
+const word = (hex: string): Uint8Array => Uint8Array.from(hex.padStart(64, hex.startsWith('f') ? 'f' : '0').match(/.{2}/g)!.map((b) => parseInt(b, 16)))
+
+describe('abi', () => {
+  it('encodeUint', () => {
+    expect(abi.encodeUint(0n)).toEqual(word('0'))
+    expect(abi.encodeUint(69n)).toEqual(word('45'))
+    expect(abi.encodeUint(2n ** 256n - 1n)).toEqual(word('f'.repeat(64)))
+    expect(abi.encodeUint(255n, 8)).toEqual(word('ff'.padStart(64, '0')))
+    expect(() => abi.encodeUint(256n, 8)).throws(RangeError)
+    expect(() => abi.encodeUint(2n ** 256n)).throws(RangeError)
+    expect(() => abi.encodeUint(-1n)).throws(RangeError)
+    expect(() => abi.encodeUint(1n, 12)).throws(RangeError)
+    expect(() => abi.encodeUint(1n, 264)).throws(RangeError)
+  })
+  it('encodeInt', () => {
+    expect(abi.encodeInt(1n)).toEqual(word('01'))
+    expect(abi.encodeInt(-1n)).toEqual(word('f'.repeat(64)))
+    expect(abi.encodeInt(-128n, 8)).toEqual(word('f80'))
+    expect(abi.encodeInt(-(2n ** 255n))).toEqual(word('8'.padEnd(64, '0')))
+    expect(() => abi.encodeInt(128n, 8)).throws(RangeError)
+    expect(() => abi.encodeInt(-129n, 8)).throws(RangeError)
+    expect(() => abi.encodeInt(2n ** 255n)).throws(RangeError)
+  })
+  it('decodeUint', () => {
+    expect(abi.decodeUint(word('45'))).eq(69n)
+    expect(abi.decodeUint(word('ff'.padStart(64, '0')), 8)).eq(255n)
+    expect(() => abi.decodeUint(word('0100'), 8)).throws(RangeError)
+    const two = new Uint8Array(64)
+    two.set(abi.encodeUint(7n), 32)
+    expect(abi.decodeUint(two, 256, 32)).eq(7n)
+    expect(() => abi.decodeUint(two, 256, 33)).throws(RangeError)
+    expect(abi.decodeUint(two, 8, 32)).eq(7n)
+  })
+  it('decodeInt', () => {
+    expect(abi.decodeInt(word('f'.repeat(64)))).eq(-1n)
+    expect(abi.decodeInt(word('f80'), 8)).eq(-128n)
+    expect(abi.decodeInt(word('7f'), 8)).eq(127n)
+    // missing sign extension
+    expect(() => abi.decodeInt(word('80'), 8)).throws(RangeError)
+    expect(() => abi.decodeInt(word('f7f'), 8)).throws(RangeError)
+    for (const num of [0n, 1n, -1n, 2n ** 255n - 1n, -(2n ** 255n), -123456789n]) {
+      expect(abi.decodeInt(abi.encodeInt(num))).eq(num)
+    }
+  })
+})
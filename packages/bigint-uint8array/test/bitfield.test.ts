@@ -0,0 +1,33 @@
+import { describe, expect, it } from 'vitest'
+import { bitfield } from '@/index.js'
+
+describe('bitfield', () => {
+  it('pack', () => {
+    expect(bitfield.pack([[0xabcn, 12], [1n, 52]])).eq(0xabc0000000000001n)
+    expect(bitfield.pack([[1n, 1], [0n, 3], [0xfn, 4]])).eq(0x8fn)
+    expect(bitfield.pack([[0n, 7], [2n ** 200n - 1n, 200]])).eq(2n ** 200n - 1n)
+    expect(bitfield.pack([])).eq(0n)
+  })
+  it('pack validation', () => {
+    expect(() => bitfield.pack([[0x1000n, 12]])).throws(RangeError)
+    expect(() => bitfield.pack([[-1n, 12]])).throws(RangeError)
+    expect(() => bitfield.pack([[0n, 0]])).throws(RangeError)
+    expect(() => bitfield.pack([[0n, 1.5]])).throws(RangeError)
+  })
+  it('unpack', () => {
+    expect(bitfield.unpack(0xabc0000000000001n, [12, 52])).toEqual([0xabcn, 1n])
+    expect(bitfield.unpack(0x8fn, [1, 3, 4])).toEqual([1n, 0n, 0xfn])
+    expect(bitfield.unpack(0n, [])).toEqual([])
+  })
+  it('unpack validation', () => {
+    expect(() => bitfield.unpack(0x100n, [4, 4])).throws(RangeError)
+    expect(() => bitfield.unpack(-1n, [8])).throws(RangeError)
+    expect(() => bitfield.unpack(0n, [8, 0])).throws(RangeError)
+  })
+  it('roundtrip', () => {
+    const widths = [3, 12, 52, 1, 64]
+    const values = [5n, 0xfffn, 2n ** 51n + 7n, 1n, 2n ** 63n]
+    const packed = bitfield.pack(values.map((v, i) => [v, widths[i]]))
+    expect(bitfield.unpack(packed, widths)).toEqual(values)
+  })
+})
@@ -0,0 +1,32 @@
+import { describe, expect, it } from 'vitest'
+import { borsh } from '@/index.js'
+
+describe('borsh', () => {
+  it('encodeUint', () => {
+    expect(borsh.encodeUint(258n, 16)).toEqual(new Uint8Array([0x02, 0x01]))
+    expect(borsh.encodeUint(258n, 128)).toEqual(Uint8Array.from({ length: 16 }, (_, i) => [0x02, 0x01][i] ?? 0))
+    expect(borsh.encodeUint(2n ** 512n - 1n, 512)).toEqual(new Uint8Array(64).fill(0xff))
+    expect(() => borsh.encodeUint(2n ** 256n, 256)).throws(RangeError)
+    expect(() => borsh.encodeUint(-1n, 128)).throws(RangeError)
+    expect(() => borsh.encodeUint(1n, 24)).throws(RangeError)
+  })
+  it('encodeInt', () => {
+    expect(borsh.encodeInt(-1n, 128)).toEqual(new Uint8Array(16).fill(0xff))
+    expect(borsh.encodeInt(-2n, 16)).toEqual(new Uint8Array([0xfe, 0xff]))
+    expect(() => borsh.encodeInt(2n ** 127n, 128)).throws(RangeError)
+    expect(() => borsh.encodeInt(-(2n ** 127n) - 1n, 128)).throws(RangeError)
+  })
+  it('decode', () => {
+    for (const bits of [8, 16, 32, 64, 128, 256, 512]) {
+      const max = 2n ** BigInt(bits) - 1n
+      const min = -(2n ** BigInt(bits - 1))
+      expect(borsh.decodeUint(borsh.encodeUint(max, bits), bits)).eq(max)
+      expect(borsh.decodeInt(borsh.encodeInt(min, bits), bits)).eq(min)
+      expect(borsh.decodeInt(borsh.encodeInt(-min - 1n, bits), bits)).eq(-min - 1n)
+    }
+    const buf = new Uint8Array([0xaa, 0x02, 0x01, 0xbb])
+    expect(borsh.decodeUint(buf, 16, 1)).eq(258n)
+    expect(() => borsh.decodeUint(buf, 32, 1)).throws(RangeError)
+    expect(() => borsh.decodeInt(buf, 24)).throws(RangeError)
+  })
+})
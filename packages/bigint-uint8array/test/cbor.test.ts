@@ -0,0 +1,41 @@
+import { describe, expect, it } from 'vitest'
+import { cbor } from '@/index.js'
+
+describe('cbor', () => {
+  // RFC 8949 appendix A examples plus edge cases
+  const vectors: Array<[bigint, number[]]> = [
+    [18446744073709551616n, [0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]],
+    [-18446744073709551617n, [0xc3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]],
+    [0n, [0xc2, 0x40]],
+    [-1n, [0xc3, 0x40]],
+    [255n, [0xc2, 0x41, 0xff]],
+    [-256n, [0xc3, 0x41, 0xff]]
+  ]
+  it('encodeBignum', () => {
+    for (const [num, bytes] of vectors) {
+      expect(cbor.encodeBignum(num)).toEqual(new Uint8Array(bytes))
+    }
+    const big = cbor.encodeBignum(2n ** 192n)
+    expect(big.subarray(0, 4)).toEqual(new Uint8Array([0xc2, 0x58, 25, 0x01]))
+    expect(cbor.encodeBignum(2n ** 2048n).subarray(0, 4)).toEqual(new Uint8Array([0xc2, 0x59, 0x01, 0x01]))
+  })
+  it('decodeBignum', () => {
+    for (const [num, bytes] of vectors) {
+      expect(cbor.decodeBignum(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    for (const num of [2n ** 192n, -(2n ** 192n), 2n ** 2048n - 1n]) {
+      const encoded = cbor.encodeBignum(num)
+      expect(cbor.decodeBignum(encoded)).toEqual({ value: num, bytesRead: encoded.length })
+    }
+    expect(cbor.decodeBignum(new Uint8Array([0x82, 0xc2, 0x41, 0x01]), 1)).toEqual({ value: 1n, bytesRead: 3 })
+  })
+  it('decodeBignum rejects malformed input', () => {
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc4, 0x41, 0x01]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2, 0x61, 0x01]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2, 0x42, 0x00, 0x01]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2, 0x58, 0x01, 0x01]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2, 0x5f, 0x41, 0x01, 0xff]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2, 0x42, 0x01]))).throws(RangeError)
+    expect(() => cbor.decodeBignum(new Uint8Array([0xc2]))).throws(RangeError)
+  })
+})
@@ -0,0 +1,30 @@
+import { describe, expect, it } from 'vitest'
+import { compactSize } from '@/index.js'
+
+describe('compactSize', () => {
+  it('encode', () => {
+    expect(compactSize.encode(0n)).toEqual(new Uint8Array([0x00]))
+    expect(compactSize.encode(252n)).toEqual(new Uint8Array([0xfc]))
+    expect(compactSize.encode(253n)).toEqual(new Uint8Array([0xfd, 0xfd, 0x00]))
+    expect(compactSize.encode(0xffffn)).toEqual(new Uint8Array([0xfd, 0xff, 0xff]))
+    expect(compactSize.encode(0x10000n)).toEqual(new Uint8Array([0xfe, 0x00, 0x00, 0x01, 0x00]))
+    expect(compactSize.encode(0x100000000n)).toEqual(new Uint8Array([0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]))
+    expect(compactSize.encode(2n ** 64n - 1n)).toEqual(new Uint8Array([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]))
+    expect(() => compactSize.encode(-1n)).throws(RangeError)
+    expect(() => compactSize.encode(2n ** 64n)).throws(RangeError)
+  })
+  it('decode', () => {
+    for (const num of [0n, 252n, 253n, 0xffffn, 0x10000n, 0xffffffffn, 0x100000000n, 2n ** 64n - 1n]) {
+      const encoded = compactSize.encode(num)
+      expect(compactSize.decode(encoded)).toEqual({ value: num, bytesRead: encoded.length })
+    }
+    expect(compactSize.decode(new Uint8Array([0xaa, 0xfd, 0x00, 0x01]), 1)).toEqual({ value: 256n, bytesRead: 3 })
+  })
+  it('decode rejects non canonical and truncated input', () => {
+    expect(() => compactSize.decode(new Uint8Array([0xfd, 0xfc, 0x00]))).throws(RangeError)
+    expect(() => compactSize.decode(new Uint8Array([0xfe, 0xff, 0xff, 0x00, 0x00]))).throws(RangeError)
+    expect(() => compactSize.decode(new Uint8Array([0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]))).throws(RangeError)
+    expect(() => compactSize.decode(new Uint8Array([0xfd, 0xff]))).throws(RangeError)
+    expect(() => compactSize.decode(new Uint8Array([]))).throws(RangeError)
+  })
+})
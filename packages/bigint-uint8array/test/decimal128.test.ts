@@ -0,0 +1,53 @@
+import { describe, expect, it } from 'vitest'
+import { decimal128 } from '@/index.js'
+
+// BSON corpus vectors are expressed as big-endian hex of the 128 bits
+const fromHex = (hex: string): Uint8Array => Uint8Array.from(hex.match(/.{2}/g)!.map((b) => parseInt(b, 16))).reverse()
+
+const MAX_COEFFICIENT = 10n ** 34n - 1n
+
+describe('decimal128', () => {
+  const vectors: Array<[bigint, string]> = [
+    [0n, '30400000000000000000000000000000'],
+    [1n, '30400000000000000000000000000001'],
+    [-1n, 'b0400000000000000000000000000001'],
+    [10n ** 34n - 1n, '3041ed09bead87c0378d8e63ffffffff'],
+    [10n ** 34n, '3042314dc6448d9338c15b0a00000000']
+  ]
+  it('encode', () => {
+    for (const [num, hex] of vectors) {
+      expect(decimal128.encode(num)).toEqual(fromHex(hex))
+    }
+    expect(() => decimal128.encode(10n ** 34n + 1n)).throws(RangeError)
+    expect(() => decimal128.encode(10n ** 6200n)).throws(RangeError)
+    expect(() => decimal128.encode(10n ** 6146n)).throws(RangeError)
+    expect(() => decimal128.encode(-(10n ** 1000000n))).throws(RangeError)
+    expect(decimal128.decode(decimal128.encode(MAX_COEFFICIENT * 10n ** 6111n))).eq(MAX_COEFFICIENT * 10n ** 6111n)
+  })
+  it('decode', () => {
+    for (const [num, hex] of vectors) {
+      expect(decimal128.decode(fromHex(hex))).eq(num)
+    }
+    // 1E+3
+    expect(decimal128.decode(fromHex('30460000000000000000000000000001'))).eq(1000n)
+    // 1000E-3 (1.000)
+    expect(decimal128.decode(fromHex('303a00000000000000000000000003e8'))).eq(1n)
+    // -0
+    expect(decimal128.decode(fromHex('b0400000000000000000000000000000'))).eq(0n)
+    // non canonical coefficients are read as zero
+    expect(decimal128.decode(fromHex('6c10000000000000000000000000000a'))).eq(0n)
+    expect(decimal128.decode(fromHex('3041ffffffffffffffffffffffffffff'))).eq(0n)
+    const shifted = new Uint8Array(18)
+    shifted.set(decimal128.encode(42n), 2)
+    expect(decimal128.decode(shifted, 2)).eq(42n)
+    expect(decimal128.decode(decimal128.encode(-(10n ** 100n)))).eq(-(10n ** 100n))
+  })
+  it('decode rejects non integers', () => {
+    // 0.1
+    expect(() => decimal128.decode(fromHex('303e0000000000000000000000000001'))).throws(RangeError)
+    // Infinity and NaN
+    expect(() => decimal128.decode(fromHex('78000000000000000000000000000000'))).throws(RangeError)
+    expect(() => decimal128.decode(fromHex('7c000000000000000000000000000000'))).throws(RangeError)
+    expect(() => decimal128.decode(new Uint8Array(15))).throws(RangeError)
+  })
+})
@@ -0,0 +1,47 @@
+import { describe, expect, it } from 'vitest'
+import { der } from '@/index.js'
+
+describe('der', () => {
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0x02, 0x01, 0x00]],
+    [1n, [0x02, 0x01, 0x01]],
+    [127n, [0x02, 0x01, 0x7f]],
+    [128n, [0x02, 0x02, 0x00, 0x80]],
+    [256n, [0x02, 0x02, 0x01, 0x00]],
+    [-1n, [0x02, 0x01, 0xff]],
+    [-128n, [0x02, 0x01, 0x80]],
+    [-129n, [0x02, 0x02, 0xff, 0x7f]],
+    [-256n, [0x02, 0x02, 0xff, 0x00]],
+    [65537n, [0x02, 0x03, 0x01, 0x00, 0x01]]
+  ]
+  it('encodeInteger', () => {
+    for (const [num, bytes] of vectors) {
+      expect(der.encodeInteger(num)).toEqual(new Uint8Array(bytes))
+    }
+    // 2^1024 needs 129 content bytes and the long length form
+    const big = der.encodeInteger(2n ** 1024n)
+    expect(big.subarray(0, 5)).toEqual(new Uint8Array([0x02, 0x81, 0x81, 0x01, 0x00]))
+    expect(big).toHaveLength(132)
+  })
+  it('decodeInteger', () => {
+    for (const [num, bytes] of vectors) {
+      expect(der.decodeInteger(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    for (const num of [2n ** 1024n, -(2n ** 1024n), 2n ** 1023n, -(2n ** 1023n) - 1n]) {
+      const encoded = der.encodeInteger(num)
+      expect(der.decodeInteger(encoded)).toEqual({ value: num, bytesRead: encoded.length })
+    }
+    expect(der.decodeInteger(new Uint8Array([0x30, 0x02, 0x01, 0x05]), 1)).toEqual({ value: 5n, bytesRead: 3 })
+  })
+  it('decodeInteger rejects non DER input', () => {
+    expect(() => der.decodeInteger(new Uint8Array([0x03, 0x01, 0x00]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x00]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x02, 0x00, 0x7f]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x02, 0xff, 0x80]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x81, 0x01, 0x00]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x82, 0x00, 0x81]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x80, 0x00, 0x00]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02, 0x02, 0x01]))).throws(RangeError)
+    expect(() => der.decodeInteger(new Uint8Array([0x02]))).throws(RangeError)
+  })
+})
@@ -0,0 +1,58 @@
+import { describe, expect, it } from 'vitest'
+import { SNOWFLAKE_TWITTER_EPOCH, snowflake, ulid, uuid, uuidv7 } from '@/index.js'
+
+describe('snowflake', () => {
+  it('decode', () => {
+    expect(snowflake.decode(1541815603606036480n)).toEqual({ timestamp: 1656432460105, workerId: 378, sequence: 0 })
+    // discord epoch
+    expect(snowflake.decode(175928847299117063n, 1420070400000)).toEqual({ timestamp: 1462015105796, workerId: 32, sequence: 7 })
+    expect(() => snowflake.decode(-1n)).throws(RangeError)
+    expect(() => snowflake.decode(2n ** 63n)).throws(RangeError)
+  })
+  it('encode', () => {
+    expect(snowflake.encode({ timestamp: 1656432460105, workerId: 378, sequence: 0 })).eq(1541815603606036480n)
+    expect(snowflake.encode({ timestamp: 1462015105796, workerId: 32, sequence: 7 }, 1420070400000)).eq(175928847299117063n)
+    expect(() => snowflake.encode({ timestamp: 0, workerId: 0, sequence: 0 })).throws(RangeError)
+    expect(() => snowflake.encode({ timestamp: SNOWFLAKE_TWITTER_EPOCH, workerId: 1024, sequence: 0 })).throws(RangeError)
+    expect(() => snowflake.encode({ timestamp: SNOWFLAKE_TWITTER_EPOCH, workerId: 0, sequence: 4096 })).throws(RangeError)
+  })
+})
+
+describe('ulid', () => {
+  const str = '01ARZ3NDEKTSV4RRFFQ69G5FAV'
+  it('parse and format', () => {
+    const value = ulid.parse(str)
+    expect(ulid.format(value)).eq(str)
+    expect(ulid.parse(str.toLowerCase())).eq(value)
+    expect(ulid.parse('7ZZZZZZZZZZZZZZZZZZZZZZZZZ')).eq(2n ** 128n - 1n)
+    expect(ulid.format(0n)).eq('00000000000000000000000000')
+    expect(() => ulid.parse('8ZZZZZZZZZZZZZZZZZZZZZZZZZ')).throws(SyntaxError)
+    expect(() => ulid.parse('01ARZ3NDEKTSV4RRFFQ69G5FAU')).throws(SyntaxError)
+    expect(() => ulid.parse(str.slice(1))).throws(SyntaxError)
+    expect(() => ulid.format(2n ** 128n)).throws(RangeError)
+  })
+  it('decode and encode', () => {
+    const value = ulid.parse(str)
+    const components = ulid.decode(value)
+    expect(components.timestamp).eq(1469922850259)
+    expect(ulid.format(components.randomness)).eq('0000000000TSV4RRFFQ69G5FAV')
+    expect(ulid.encode(components)).eq(value)
+    expect(() => ulid.encode({ timestamp: 2 ** 48, randomness: 0n })).throws(RangeError)
+    expect(() => ulid.encode({ timestamp: 0, randomness: 2n ** 80n })).throws(RangeError)
+  })
+})
+
+describe('uuidv7', () => {
+  const value = uuid.parse('017f22e2-79b0-7cc3-98c4-dc0c0c07398f')
+  it('decode', () => {
+    expect(uuidv7.decode(value)).toEqual({ timestamp: 1645557742000, randA: 0xcc3, randB: 0x18c4dc0c0c07398fn })
+    expect(() => uuidv7.decode(uuid.parse('f81d4fae-7dec-11d0-a765-00a0c91e6bf6'))).throws(RangeError)
+    expect(() => uuidv7.decode(uuid.parse('017f22e2-79b0-7cc3-c8c4-dc0c0c07398f'))).throws(RangeError)
+  })
+  it('encode', () => {
+    expect(uuidv7.encode({ timestamp: 1645557742000, randA: 0xcc3, randB: 0x18c4dc0c0c07398fn })).eq(value)
+    expect(uuid.format(uuidv7.encode({ timestamp: 0, randA: 0, randB: 0n }))).eq('00000000-0000-7000-8000-000000000000')
+    expect(() => uuidv7.encode({ timestamp: 0, randA: 0x1000, randB: 0n })).throws(RangeError)
+    expect(() => uuidv7.encode({ timestamp: 0, randA: 0, randB: 2n ** 62n })).throws(RangeError)
+  })
+})
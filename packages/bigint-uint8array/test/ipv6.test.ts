@@ -0,0 +1,50 @@
+import { describe, expect, it } from 'vitest'
+import { ipv6 } from '@/index.js'
+
+describe('ipv6', () => {
+  it('parse', () => {
+    expect(ipv6.parse('2001:db8::1')).eq(0x20010db8000000000000000000000001n)
+    expect(ipv6.parse('2001:0DB8:0000:0000:0000:0000:0000:0001')).eq(0x20010db8000000000000000000000001n)
+    expect(ipv6.parse('::')).eq(0n)
+    expect(ipv6.parse('::1')).eq(1n)
+    expect(ipv6.parse('fe80::')).eq(0xfe80n << 112n)
+    expect(ipv6.parse('::ffff:192.0.2.1')).eq(0xffffc0000201n)
+    expect(ipv6.parse('1:2:3:4:5:6:1.2.3.4')).eq(0x00010002000300040005000601020304n)
+    expect(ipv6.parse('ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff')).eq(2n ** 128n - 1n)
+  })
+  it('parse rejects invalid addresses', () => {
+    for (const str of ['', ':', '1::2::3', '1:2:3:4:5:6:7', '1:2:3:4:5:6:7:8:9', '1:2:3:4::5:6:7:8',
+      '12345::', 'g::', '::1.2.3.256', '1.2.3.4::', '::1.2.3.4:1', 'fe80::1%eth0', ':1::', '1:::2']) {
+      expect(() => ipv6.parse(str), str).throws(SyntaxError)
+    }
+  })
+  it('format', () => {
+    expect(ipv6.format(0x20010db8000000000000000000000001n)).eq('2001:db8::1')
+    expect(ipv6.format(0n)).eq('::')
+    expect(ipv6.format(1n)).eq('::1')
+    expect(ipv6.format(0xfe80n << 112n)).eq('fe80::')
+    // single zero groups are not compressed, ties compress the first run
+    expect(ipv6.format(ipv6.parse('2001:db8:0:1:1:1:1:1'))).eq('2001:db8:0:1:1:1:1:1')
+    expect(ipv6.format(ipv6.parse('2001:0:0:1:0:0:1:1'))).eq('2001::1:0:0:1:1')
+    expect(ipv6.format(ipv6.parse('2001:0:0:1:0:0:0:1'))).eq('2001:0:0:1::1')
+    expect(() => ipv6.format(-1n)).throws(RangeError)
+    expect(() => ipv6.format(2n ** 128n)).throws(RangeError)
+  })
+  it('networkRange', () => {
+    const { first, last } = ipv6.networkRange(ipv6.parse('2001:db8:1234::1'), 32)
+    expect(ipv6.format(first)).eq('2001:db8::')
+    expect(ipv6.format(last)).eq('2001:db8:ffff:ffff:ffff:ffff:ffff:ffff')
+    expect(ipv6.networkRange(5n, 128)).toEqual({ first: 5n, last: 5n })
+    expect(ipv6.networkRange(5n, 0)).toEqual({ first: 0n, last: 2n ** 128n - 1n })
+    expect(() => ipv6.networkRange(0n, 129)).throws(RangeError)
+    expect(() => ipv6.networkRange(0n, -1)).throws(RangeError)
+  })
+  it('contains', () => {
+    const net = ipv6.parse('2001:db8::')
+    expect(ipv6.contains(net, 32, ipv6.parse('2001:db8:ffff::1'))).eq(true)
+    expect(ipv6.contains(net, 32, ipv6.parse('2001:db9::'))).eq(false)
+    expect(ipv6.contains(net, 0, 1n)).eq(true)
+    expect(ipv6.contains(net, 128, net)).eq(true)
+    expect(ipv6.contains(net, 128, net + 1n)).eq(false)
+  })
+})
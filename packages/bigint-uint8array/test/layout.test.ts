@@ -0,0 +1,47 @@
+import { describe, expect, it } from 'vitest'
+import { createLayout } from '@/index.js'
+
+describe('layout', () => {
+  const layout = createLayout([
+    { bytes: 2 },
+    { bytes: 2, endian: 'le' },
+    { padding: 1 },
+    { bytes: 1, signed: true },
+    { bytes: 16, endian: 'le', signed: true }
+  ])
+  it('size', () => {
+    expect(layout.size).eq(22)
+    expect(createLayout([]).size).eq(0)
+  })
+  it('pack', () => {
+    const buf = layout.pack([0x0102n, 0x0304n, -2n, -1n])
+    expect(buf.subarray(0, 6)).toEqual(new Uint8Array([0x01, 0x02, 0x04, 0x03, 0x00, 0xfe]))
+    expect(buf.subarray(6)).toEqual(new Uint8Array(16).fill(0xff))
+  })
+  it('packInto', () => {
+    const dest = new Uint8Array(24).fill(0xaa)
+    layout.packInto([1n, 2n, 3n, 4n], dest, 1)
+    expect(dest[0]).eq(0xaa)
+    expect(dest[5]).eq(0x00)
+    expect(dest[23]).eq(0xaa)
+    expect(layout.unpack(dest, 1)).toEqual([1n, 2n, 3n, 4n])
+    expect(() => layout.packInto([1n, 2n, 3n, 4n], dest, 3)).throws(RangeError)
+  })
+  it('unpack', () => {
+    const values = [0xffffn, 0n, -128n, -(2n ** 127n)]
+    expect(layout.unpack(layout.pack(values))).toEqual(values)
+    expect(() => layout.unpack(new Uint8Array(21))).throws(RangeError)
+  })
+  it('validates values', () => {
+    expect(() => layout.pack([1n, 2n, 3n])).throws(RangeError)
+    expect(() => layout.pack([0x10000n, 0n, 0n, 0n])).throws()
+    expect(() => layout.pack([-1n, 0n, 0n, 0n])).throws()
+    expect(() => layout.pack([0n, 0n, 128n, 0n])).throws()
+  })
+  it('validates fields', () => {
+    expect(() => createLayout([{ bytes: 0 }])).throws(RangeError)
+    expect(() => createLayout([{ bytes: 1.5 }])).throws(RangeError)
+    expect(() => createLayout([{ padding: -1 }])).throws(RangeError)
+    expect(() => createLayout([{ padding: 0 }])).not.throws()
+  })
+})
@@ -0,0 +1,42 @@
+import { describe, expect, it } from 'vitest'
+import { leb128 } from '@/index.js'
+
+describe('leb128', () => {
+  it('encodeUnsigned', () => {
+    expect(leb128.encodeUnsigned(0n)).toEqual(new Uint8Array([0x00]))
+    expect(leb128.encodeUnsigned(127n)).toEqual(new Uint8Array([0x7f]))
+    expect(leb128.encodeUnsigned(128n)).toEqual(new Uint8Array([0x80, 0x01]))
+    expect(leb128.encodeUnsigned(624485n)).toEqual(new Uint8Array([0xe5, 0x8e, 0x26]))
+    expect(() => leb128.encodeUnsigned(-1n)).throws(RangeError)
+  })
+  it('decodeUnsigned', () => {
+    expect(leb128.decodeUnsigned(new Uint8Array([0xe5, 0x8e, 0x26]))).toEqual({ value: 624485n, bytesRead: 3 })
+    expect(leb128.decodeUnsigned(new Uint8Array([0xff, 0x80, 0x01, 0x00]), 1)).toEqual({ value: 128n, bytesRead: 2 })
+    // non canonical padding is accepted
+    expect(leb128.decodeUnsigned(new Uint8Array([0x80, 0x80, 0x00]))).toEqual({ value: 0n, bytesRead: 3 })
+    expect(() => leb128.decodeUnsigned(new Uint8Array([0x80]))).throws(RangeError)
+    expect(() => leb128.decodeUnsigned(new Uint8Array([]))).throws(RangeError)
+  })
+  it('encodeSigned', () => {
+    expect(leb128.encodeSigned(0n)).toEqual(new Uint8Array([0x00]))
+    expect(leb128.encodeSigned(-1n)).toEqual(new Uint8Array([0x7f]))
+    expect(leb128.encodeSigned(63n)).toEqual(new Uint8Array([0x3f]))
+    expect(leb128.encodeSigned(64n)).toEqual(new Uint8Array([0xc0, 0x00]))
+    expect(leb128.encodeSigned(-64n)).toEqual(new Uint8Array([0x40]))
+    expect(leb128.encodeSigned(-65n)).toEqual(new Uint8Array([0xbf, 0x7f]))
+    expect(leb128.encodeSigned(-123456n)).toEqual(new Uint8Array([0xc0, 0xbb, 0x78]))
+  })
+  it('decodeSigned', () => {
+    expect(leb128.decodeSigned(new Uint8Array([0xc0, 0xbb, 0x78]))).toEqual({ value: -123456n, bytesRead: 3 })
+    expect(leb128.decodeSigned(new Uint8Array([0x7f]))).toEqual({ value: -1n, bytesRead: 1 })
+    expect(leb128.decodeSigned(new Uint8Array([0xc0, 0x00]))).toEqual({ value: 64n, bytesRead: 2 })
+    expect(() => leb128.decodeSigned(new Uint8Array([0xc0]))).throws(RangeError)
+  })
+  it('roundtrips values wider than 128 bits', () => {
+    for (const num of [2n ** 64n, 2n ** 128n + 12345n, 2n ** 300n - 1n]) {
+      expect(leb128.decodeUnsigned(leb128.encodeUnsigned(num)).value).eq(num)
+      expect(leb128.decodeSigned(leb128.encodeSigned(num)).value).eq(num)
+      expect(leb128.decodeSigned(leb128.encodeSigned(-num)).value).eq(-num)
+    }
+  })
+})
@@ -0,0 +1,52 @@
+import { describe, expect, it } from 'vitest'
+import { memcomparable } from '@/index.js'
+
+const compareBytes = (a: Uint8Array, b: Uint8Array): number => {
+  for (let i = 0; i < Math.min(a.length, b.length); i++) {
+    if (a[i] !== b[i]) {
+      return a[i] < b[i] ? -1 : 1
+    }
+  }
+  return Math.sign(a.length - b.length)
+}
+
+describe('memcomparable', () => {
+  const values = [
+    -(2n ** 2000n), -(2n ** 1008n), -(2n ** 1000n), -65536n, -65535n, -256n, -255n, -2n, -1n,
+    0n, 1n, 2n, 127n, 128n, 255n, 256n, 65535n, 65536n, 2n ** 64n, 2n ** 1000n, 2n ** 1008n, 2n ** 2000n
+  ]
+  it('encode', () => {
+    expect(memcomparable.encode(0n)).toEqual(new Uint8Array([0x80]))
+    expect(memcomparable.encode(1n)).toEqual(new Uint8Array([0x81, 0x01]))
+    expect(memcomparable.encode(258n)).toEqual(new Uint8Array([0x82, 0x01, 0x02]))
+    expect(memcomparable.encode(-1n)).toEqual(new Uint8Array([0x7e, 0xfe]))
+    expect(memcomparable.encode(-258n)).toEqual(new Uint8Array([0x7d, 0xfe, 0xfd]))
+    expect(memcomparable.encode(2n ** 1008n).subarray(0, 6)).toEqual(new Uint8Array([0xff, 0x00, 0x00, 0x00, 127, 0x01]))
+  })
+  it('preserves ordering', () => {
+    const encoded = values.map((v) => memcomparable.encode(v))
+    for (let i = 0; i < values.length; i++) {
+      for (let j = 0; j < values.length; j++) {
+        expect(compareBytes(encoded[i], encoded[j])).eq(Math.sign(i - j))
+      }
+    }
+  })
+  it('decode', () => {
+    for (const value of values) {
+      const encoded = memcomparable.encode(value)
+      expect(memcomparable.decode(encoded)).toEqual({ value, bytesRead: encoded.length })
+    }
+    const composite = new Uint8Array([...memcomparable.encode(-5n), ...memcomparable.encode(300n)])
+    expect(memcomparable.decode(composite)).toEqual({ value: -5n, bytesRead: 2 })
+    expect(memcomparable.decode(composite, 2)).toEqual({ value: 300n, bytesRead: 3 })
+  })
+  it('decode rejects non canonical input', () => {
+    expect(() => memcomparable.decode(new Uint8Array([0x82, 0x00, 0x01]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([0x7d, 0xff, 0xfe]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([0xff, 0x00, 0x00, 0x00, 0x01, 0x01]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([0x82, 0x01]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([0x7f]))).throws(RangeError)
+    expect(() => memcomparable.decode(new Uint8Array([0x7f, 0x81, 0x01]))).throws(RangeError)
+  })
+})
@@ -0,0 +1,57 @@
+import { describe, expect, it } from 'vitest'
+import { mpint, openpgpMpi } from '@/index.js'
+
+describe('mpint', () => {
+  // RFC 4251 section 5 examples
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0x00, 0x00, 0x00, 0x00]],
+    [0x9a378f9b2e332a7n, [0x00, 0x00, 0x00, 0x08, 0x09, 0xa3, 0x78, 0xf9, 0xb2, 0xe3, 0x32, 0xa7]],
+    [0x80n, [0x00, 0x00, 0x00, 0x02, 0x00, 0x80]],
+    [-0x1234n, [0x00, 0x00, 0x00, 0x02, 0xed, 0xcc]],
+    [-0xdeadbeefn, [0x00, 0x00, 0x00, 0x05, 0xff, 0x21, 0x52, 0x41, 0x11]]
+  ]
+  it('encode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(mpint.encode(num)).toEqual(new Uint8Array(bytes))
+    }
+  })
+  it('decode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(mpint.decode(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    const shifted = new Uint8Array([0xaa, 0x00, 0x00, 0x00, 0x01, 0x7f, 0xbb])
+    expect(mpint.decode(shifted, 1)).toEqual({ value: 127n, bytesRead: 5 })
+    expect(mpint.decode(shifted.subarray(1))).toEqual({ value: 127n, bytesRead: 5 })
+  })
+  it('decode rejects non minimal and truncated input', () => {
+    expect(() => mpint.decode(new Uint8Array([0x00, 0x00, 0x00, 0x02, 0x00, 0x7f]))).throws(RangeError)
+    expect(() => mpint.decode(new Uint8Array([0x00, 0x00, 0x00, 0x02, 0xff, 0x80]))).throws(RangeError)
+    expect(() => mpint.decode(new Uint8Array([0x00, 0x00, 0x00, 0x01, 0x00]))).throws(RangeError)
+    expect(() => mpint.decode(new Uint8Array([0x00, 0x00, 0x00, 0x02, 0x01]))).throws(RangeError)
+    expect(() => mpint.decode(new Uint8Array([0x00, 0x00, 0x00]))).throws(RangeError)
+  })
+})
+
+describe('openpgpMpi', () => {
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0x00, 0x00]],
+    [1n, [0x00, 0x01, 0x01]],
+    [511n, [0x00, 0x09, 0x01, 0xff]],
+    [0x8000n, [0x00, 0x10, 0x80, 0x00]]
+  ]
+  it('encode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(openpgpMpi.encode(num)).toEqual(new Uint8Array(bytes))
+    }
+    expect(() => openpgpMpi.encode(-1n)).throws(RangeError)
+    expect(() => openpgpMpi.encode(2n ** 65535n)).throws(RangeError)
+  })
+  it('decode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(openpgpMpi.decode(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    expect(() => openpgpMpi.decode(new Uint8Array([0x00, 0x0a, 0x01, 0xff]))).throws(RangeError)
+    expect(() => openpgpMpi.decode(new Uint8Array([0x00, 0x09, 0x00, 0xff]))).throws(RangeError)
+    expect(() => openpgpMpi.decode(new Uint8Array([0x00, 0x09, 0x01]))).throws(RangeError)
+  })
+})
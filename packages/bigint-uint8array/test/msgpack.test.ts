@@ -0,0 +1,41 @@
+import { describe, expect, it } from 'vitest'
+import { msgpack } from '@/index.js'
+
+describe('msgpack', () => {
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0xd4, 0x05, 0x00]],
+    [-1n, [0xd4, 0x05, 0xff]],
+    [0x1234n, [0xd5, 0x05, 0x12, 0x34]],
+    [0x123456n, [0xc7, 0x03, 0x05, 0x12, 0x34, 0x56]],
+    [-0x800000n, [0xc7, 0x03, 0x05, 0x80, 0x00, 0x00]],
+    [0x7fffffffn, [0xd6, 0x05, 0x7f, 0xff, 0xff, 0xff]],
+    [0x80000000n, [0xc7, 0x05, 0x05, 0x00, 0x80, 0x00, 0x00, 0x00]]
+  ]
+  it('encodeExt', () => {
+    for (const [num, bytes] of vectors) {
+      expect(msgpack.encodeExt(num, 5)).toEqual(new Uint8Array(bytes))
+    }
+    expect(msgpack.encodeExt(1n, -2).subarray(0, 2)).toEqual(new Uint8Array([0xd4, 0xfe]))
+    expect(msgpack.encodeExt(2n ** 127n - 1n, 1)[0]).eq(0xd8)
+    expect(msgpack.encodeExt(2n ** 2048n, 1).subarray(0, 4)).toEqual(new Uint8Array([0xc8, 0x01, 0x01, 0x01]))
+    expect(() => msgpack.encodeExt(1n, 128)).throws(RangeError)
+    expect(() => msgpack.encodeExt(1n, -129)).throws(RangeError)
+  })
+  it('decodeExt', () => {
+    for (const [num, bytes] of vectors) {
+      expect(msgpack.decodeExt(new Uint8Array(bytes), 5)).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    for (const num of [2n ** 127n - 1n, -(2n ** 127n), 2n ** 2048n, -(2n ** 100000n)]) {
+      const encoded = msgpack.encodeExt(num, -3)
+      expect(msgpack.decodeExt(encoded, -3)).toEqual({ value: num, bytesRead: encoded.length })
+    }
+    expect(msgpack.decodeExt(new Uint8Array([0x92, 0xd4, 0x05, 0x07]), 5, 1)).toEqual({ value: 7n, bytesRead: 3 })
+  })
+  it('decodeExt rejects malformed input', () => {
+    expect(() => msgpack.decodeExt(new Uint8Array([0xd4, 0x06, 0x01]), 5)).throws(RangeError)
+    expect(() => msgpack.decodeExt(new Uint8Array([0xcf, 0x05, 0x01]), 5)).throws(RangeError)
+    expect(() => msgpack.decodeExt(new Uint8Array([0xc7, 0x00, 0x05]), 5)).throws(RangeError)
+    expect(() => msgpack.decodeExt(new Uint8Array([0xd5, 0x05, 0x01]), 5)).throws(RangeError)
+    expect(() => msgpack.decodeExt(new Uint8Array([0xc8, 0x00]), 5)).throws(RangeError)
+  })
+})
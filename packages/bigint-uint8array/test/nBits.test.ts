@@ -0,0 +1,46 @@
+import { describe, expect, it } from 'vitest'
+import { nBits } from '@/index.js'
+
+describe('nBits', () => {
+  // test vectors from Bitcoin Core's arith_uint256 tests
+  it('compactToTarget', () => {
+    expect(nBits.compactToTarget(0)).eq(0n)
+    expect(nBits.compactToTarget(0x00123456)).eq(0n)
+    expect(nBits.compactToTarget(0x01003456)).eq(0n)
+    expect(nBits.compactToTarget(0x02000056)).eq(0n)
+    expect(nBits.compactToTarget(0x03000000)).eq(0n)
+    expect(nBits.compactToTarget(0x04000000)).eq(0n)
+    expect(nBits.compactToTarget(0x00923456)).eq(0n)
+    expect(nBits.compactToTarget(0x01803456)).eq(0n)
+    expect(nBits.compactToTarget(0x01123456)).eq(0x12n)
+    expect(nBits.compactToTarget(0x01fedcba)).eq(-0x7en)
+    expect(nBits.compactToTarget(0x02123456)).eq(0x1234n)
+    expect(nBits.compactToTarget(0x03123456)).eq(0x123456n)
+    expect(nBits.compactToTarget(0x04123456)).eq(0x12345600n)
+    expect(nBits.compactToTarget(0x04923456)).eq(-0x12345600n)
+    expect(nBits.compactToTarget(0x05009234)).eq(0x92340000n)
+    expect(nBits.compactToTarget(0x20123456)).eq(0x123456n << 232n)
+    expect(nBits.compactToTarget(0x1d00ffff)).eq(0xffffn << 208n)
+  })
+  it('compactToTarget overflow', () => {
+    expect(() => nBits.compactToTarget(0xff123456)).throws(RangeError)
+    expect(() => nBits.compactToTarget(0x22000001)).not.throws()
+    expect(() => nBits.compactToTarget(0x22000100)).throws(RangeError)
+    expect(() => nBits.compactToTarget(-1)).throws(RangeError)
+    expect(() => nBits.compactToTarget(2 ** 32)).throws(RangeError)
+  })
+  it('targetToCompact', () => {
+    expect(nBits.targetToCompact(0n)).eq(0)
+    expect(nBits.targetToCompact(0x12n)).eq(0x01120000)
+    expect(nBits.targetToCompact(-0x7en)).eq(0x01fe0000)
+    expect(nBits.targetToCompact(0x80n)).eq(0x02008000)
+    expect(nBits.targetToCompact(0x1234n)).eq(0x02123400)
+    expect(nBits.targetToCompact(0x123456n)).eq(0x03123456)
+    expect(nBits.targetToCompact(0x12345600n)).eq(0x04123456)
+    expect(nBits.targetToCompact(-0x12345600n)).eq(0x04923456)
+    expect(nBits.targetToCompact(0x92340000n)).eq(0x05009234)
+    expect(nBits.targetToCompact(0x123456n << 232n)).eq(0x20123456)
+    expect(nBits.targetToCompact(0xffffn << 208n)).eq(0x1d00ffff)
+    expect(nBits.targetToCompact(0x123456789n)).eq(0x05012345)
+  })
+})
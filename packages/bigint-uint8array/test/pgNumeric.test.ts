@@ -0,0 +1,61 @@
+import { describe, expect, it } from 'vitest'
+import { pgNumeric } from '@/index.js'
+
+const numeric = (ndigits: number, weight: number, sign: number, dscale: number, digits: number[]): Uint8Array => {
+  const toRet = new Uint8Array(8 + digits.length * 2)
+  const view = new DataView(toRet.buffer)
+  view.setInt16(0, ndigits)
+  view.setInt16(2, weight)
+  view.setUint16(4, sign)
+  view.setUint16(6, dscale)
+  digits.forEach((digit, i) => view.setInt16(8 + i * 2, digit))
+  return toRet
+}
+
+const NBASE = 10000n
+
+describe('pgNumeric', () => {
+  const vectors: Array<[bigint, Uint8Array]> = [
+    [0n, numeric(0, 0, 0, 0, [])],
+    [1n, numeric(1, 0, 0, 0, [1])],
+    [-1n, numeric(1, 0, 0x4000, 0, [1])],
+    [10000n, numeric(1, 1, 0, 0, [1])],
+    [123456789n, numeric(3, 2, 0, 0, [1, 2345, 6789])],
+    [-100000000n, numeric(1, 2, 0x4000, 0, [1])],
+    [10n ** 40n + 1n, numeric(11, 10, 0, 0, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])]
+  ]
+  it('encode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(pgNumeric.encode(num)).toEqual(bytes)
+    }
+    expect(() => pgNumeric.encode(10n ** (4n * 32769n))).throws(RangeError)
+    expect(() => pgNumeric.encode(-(10000n ** 32768n))).throws(RangeError)
+  })
+  it('decode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(pgNumeric.decode(bytes)).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    // 12.00 with dscale 2
+    expect(pgNumeric.decode(numeric(2, 0, 0, 2, [12, 0])).value).eq(12n)
+    const shifted = new Uint8Array(12)
+    shifted.set(numeric(1, 0, 0, 0, [7]), 2)
+    expect(pgNumeric.decode(shifted, 2)).toEqual({ value: 7n, bytesRead: 10 })
+    // trailing zero digits omitted, weight beyond ndigits
+    expect(pgNumeric.decode(numeric(2, 5, 0x4000, 0, [12, 34])).value).eq(-1200340000000000000000n)
+  })
+  it('decode large values', () => {
+    const nines = numeric(0x7fff, 0x7ffe, 0, 0, new Array<number>(0x7fff).fill(9999))
+    expect(pgNumeric.decode(nines)).toEqual({ value: 10n ** (4n * 0x7fffn) - 1n, bytesRead: nines.length })
+    const sparse = numeric(0x7fff, 0x7fff, 0, 0, Array.from({ length: 0x7fff }, (_, i) => i % 10000))
+    expect(pgNumeric.decode(sparse).value % (NBASE * NBASE)).eq(2766n * NBASE)
+  })
+  it('decode rejects non integer values', () => {
+    expect(() => pgNumeric.decode(numeric(2, 0, 0, 1, [12, 5000]))).throws(RangeError)
+    expect(() => pgNumeric.decode(numeric(1, -1, 0, 4, [5]))).throws(RangeError)
+    expect(() => pgNumeric.decode(numeric(0, 0, 0xc000, 0, []))).throws(RangeError)
+    expect(() => pgNumeric.decode(numeric(0, 0, 0xd000, 0, []))).throws(RangeError)
+    expect(() => pgNumeric.decode(numeric(1, 0, 0, 0, [10000]))).throws(RangeError)
+    expect(() => pgNumeric.decode(numeric(2, 0, 0, 0, [1]))).throws(RangeError)
+    expect(() => pgNumeric.decode(new Uint8Array(7))).throws(RangeError)
+  })
+})
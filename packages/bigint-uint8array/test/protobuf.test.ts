@@ -0,0 +1,38 @@
+import { describe, expect, it } from 'vitest'
+import { protobuf } from '@/index.js'
+
+describe('protobuf', () => {
+  it('encodeVarint', () => {
+    expect(protobuf.encodeVarint(1n)).toEqual(new Uint8Array([0x01]))
+    expect(protobuf.encodeVarint(150n)).toEqual(new Uint8Array([0x96, 0x01]))
+    expect(protobuf.encodeVarint(300n)).toEqual(new Uint8Array([0xac, 0x02]))
+    expect(protobuf.encodeVarint(-1n)).toEqual(new Uint8Array([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]))
+    expect(protobuf.encodeVarint(2n ** 64n - 1n)).toEqual(protobuf.encodeVarint(-1n))
+    expect(() => protobuf.encodeVarint(2n ** 64n)).throws(RangeError)
+    expect(() => protobuf.encodeVarint(-(2n ** 63n) - 1n)).throws(RangeError)
+  })
+  it('decodeVarint', () => {
+    expect(protobuf.decodeVarint(new Uint8Array([0x08, 0x96, 0x01]), 1)).toEqual({ value: 150n, bytesRead: 2 })
+    const minusOne = protobuf.decodeVarint(protobuf.encodeVarint(-1n))
+    expect(minusOne).toEqual({ value: 2n ** 64n - 1n, bytesRead: 10 })
+    expect(BigInt.asIntN(64, minusOne.value)).eq(-1n)
+    // 11 bytes
+    expect(() => protobuf.decodeVarint(new Uint8Array([0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]))).throws(RangeError)
+    // 10 bytes but more than 64 bits
+    expect(() => protobuf.decodeVarint(new Uint8Array([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]))).throws(RangeError)
+    expect(() => protobuf.decodeVarint(new Uint8Array([0x96]))).throws(RangeError)
+  })
+  it('zigzag', () => {
+    const cases: Array<[bigint, bigint]> = [
+      [0n, 0n], [-1n, 1n], [1n, 2n], [-2n, 3n],
+      [2147483647n, 4294967294n], [-2147483648n, 4294967295n],
+      [-(2n ** 63n), 2n ** 64n - 1n]
+    ]
+    for (const [signed, unsigned] of cases) {
+      expect(protobuf.zigzagEncode(signed)).eq(unsigned)
+      expect(protobuf.zigzagDecode(unsigned)).eq(signed)
+    }
+    expect(protobuf.zigzagDecode(protobuf.zigzagEncode(-(2n ** 200n)))).eq(-(2n ** 200n))
+    expect(() => protobuf.zigzagDecode(-1n)).throws(RangeError)
+  })
+})
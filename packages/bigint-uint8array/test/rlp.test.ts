@@ -0,0 +1,40 @@
+import { describe, expect, it } from 'vitest'
+import { rlp } from '@/index.js'
+
+describe('rlp', () => {
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0x80]],
+    [1n, [0x01]],
+    [0x7fn, [0x7f]],
+    [0x80n, [0x81, 0x80]],
+    [1000n, [0x82, 0x03, 0xe8]],
+    [100000n, [0x83, 0x01, 0x86, 0xa0]],
+    [0x102030405060708090a0b0c0d0e0f2n, [0x8f, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80, 0x90, 0xa0, 0xb0, 0xc0, 0xd0, 0xe0, 0xf2]]
+  ]
+  it('encodeUint', () => {
+    for (const [num, bytes] of vectors) {
+      expect(rlp.encodeUint(num)).toEqual(new Uint8Array(bytes))
+    }
+    const long = rlp.encodeUint(2n ** 448n)
+    expect(long.subarray(0, 3)).toEqual(new Uint8Array([0xb8, 57, 0x01]))
+    expect(long).toHaveLength(59)
+    expect(() => rlp.encodeUint(-1n)).throws(RangeError)
+  })
+  it('decodeUint', () => {
+    for (const [num, bytes] of vectors) {
+      expect(rlp.decodeUint(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    expect(rlp.decodeUint(rlp.encodeUint(2n ** 448n))).toEqual({ value: 2n ** 448n, bytesRead: 59 })
+    expect(rlp.decodeUint(new Uint8Array([0xc0, 0x82, 0x04, 0x00]), 1)).toEqual({ value: 1024n, bytesRead: 3 })
+  })
+  it('decodeUint rejects non canonical input', () => {
+    expect(() => rlp.decodeUint(new Uint8Array([0x82, 0x00, 0x01]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0x81, 0x00]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0x81, 0x7f]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0xb8, 0x01, 0x80]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0xb9, 0x00, 0x38]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0xc0]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([0x82, 0x01]))).throws(RangeError)
+    expect(() => rlp.decodeUint(new Uint8Array([]))).throws(RangeError)
+  })
+})
@@ -0,0 +1,43 @@
+import { describe, expect, it } from 'vitest'
+import { scaleCompact } from '@/index.js'
+
+describe('scaleCompact', () => {
+  const vectors: Array<[bigint, number[]]> = [
+    [0n, [0x00]],
+    [1n, [0x04]],
+    [42n, [0xa8]],
+    [63n, [0xfc]],
+    [64n, [0x01, 0x01]],
+    [69n, [0x15, 0x01]],
+    [16383n, [0xfd, 0xff]],
+    [16384n, [0x02, 0x00, 0x01, 0x00]],
+    [1073741823n, [0xfe, 0xff, 0xff, 0xff]],
+    [1073741824n, [0x03, 0x00, 0x00, 0x00, 0x40]],
+    [2n ** 32n - 1n, [0x03, 0xff, 0xff, 0xff, 0xff]],
+    [2n ** 32n, [0x07, 0x00, 0x00, 0x00, 0x00, 0x01]],
+    [2n ** 64n - 1n, [0x13, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]]
+  ]
+  it('encode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(scaleCompact.encode(num)).toEqual(new Uint8Array(bytes))
+    }
+    expect(scaleCompact.encode(2n ** 536n - 1n)).toHaveLength(68)
+    expect(() => scaleCompact.encode(2n ** 536n)).throws(RangeError)
+    expect(() => scaleCompact.encode(-1n)).throws(RangeError)
+  })
+  it('decode', () => {
+    for (const [num, bytes] of vectors) {
+      expect(scaleCompact.decode(new Uint8Array(bytes))).toEqual({ value: num, bytesRead: bytes.length })
+    }
+    expect(scaleCompact.decode(new Uint8Array([0xff, 0x15, 0x01]), 1)).toEqual({ value: 69n, bytesRead: 2 })
+    expect(scaleCompact.decode(scaleCompact.encode(2n ** 536n - 1n)).value).eq(2n ** 536n - 1n)
+  })
+  it('decode rejects non canonical and truncated input', () => {
+    expect(() => scaleCompact.decode(new Uint8Array([0x01, 0x00]))).throws(RangeError)
+    expect(() => scaleCompact.decode(new Uint8Array([0x02, 0x01, 0x00, 0x00]))).throws(RangeError)
+    expect(() => scaleCompact.decode(new Uint8Array([0x03, 0xff, 0xff, 0xff, 0x3f]))).throws(RangeError)
+    expect(() => scaleCompact.decode(new Uint8Array([0x07, 0xff, 0xff, 0xff, 0xff, 0x00]))).throws(RangeError)
+    expect(() => scaleCompact.decode(new Uint8Array([0x01]))).throws(RangeError)
+    expect(() => scaleCompact.decode(new Uint8Array([0x07, 0x00, 0x00, 0x00, 0x00]))).throws(RangeError)
+  })
+})
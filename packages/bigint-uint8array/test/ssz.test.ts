@@ -0,0 +1,28 @@
+import { describe, expect, it } from 'vitest'
+import { ssz } from '@/index.js'
+
+describe('ssz', () => {
+  it('encodeUint', () => {
+    expect(ssz.encodeUint(0x01n, 8)).toEqual(new Uint8Array([0x01]))
+    expect(ssz.encodeUint(0x0102n, 16)).toEqual(new Uint8Array([0x02, 0x01]))
+    expect(ssz.encodeUint(1n, 64)).toEqual(new Uint8Array([1, 0, 0, 0, 0, 0, 0, 0]))
+    expect(ssz.encodeUint(2n ** 128n - 1n, 128)).toEqual(new Uint8Array(16).fill(0xff))
+    expect(ssz.encodeUint(2n ** 255n, 256)).toEqual(Uint8Array.from({ length: 32 }, (_, i) => i === 31 ? 0x80 : 0))
+    expect(() => ssz.encodeUint(256n, 8)).throws(RangeError)
+    expect(() => ssz.encodeUint(-1n, 64)).throws(RangeError)
+    expect(() => ssz.encodeUint(1n, 24)).throws(RangeError)
+  })
+  it('decodeUint', () => {
+    expect(ssz.decodeUint(new Uint8Array([0x02, 0x01]), 16)).eq(0x0102n)
+    for (const bits of [8, 16, 32, 64, 128, 256]) {
+      const max = 2n ** BigInt(bits) - 1n
+      expect(ssz.decodeUint(ssz.encodeUint(max, bits), bits)).eq(max)
+    }
+  })
+  it('decodeUint rejects extra or missing bytes', () => {
+    expect(() => ssz.decodeUint(new Uint8Array(9), 64)).throws(RangeError)
+    expect(() => ssz.decodeUint(new Uint8Array(7), 64)).throws(RangeError)
+    expect(() => ssz.decodeUint(new Uint8Array(0), 8)).throws(RangeError)
+    expect(() => ssz.decodeUint(new Uint8Array(3), 24)).throws(RangeError)
+  })
+})
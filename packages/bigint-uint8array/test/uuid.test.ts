@@ -0,0 +1,44 @@
+import { describe, expect, it } from 'vitest'
+import { uuid } from '@/index.js'
+
+describe('uuid', () => {
+  const str = 'f81d4fae-7dec-11d0-a765-00a0c91e6bf6'
+  const value = 0xf81d4fae7dec11d0a76500a0c91e6bf6n
+  it('parse', () => {
+    expect(uuid.parse(str)).eq(value)
+    expect(uuid.parse(str.toUpperCase())).eq(value)
+    expect(uuid.parse('00000000-0000-0000-0000-000000000000')).eq(0n)
+    expect(uuid.parse('ffffffff-ffff-ffff-ffff-ffffffffffff')).eq(2n ** 128n - 1n)
+  })
+  it('parse rejects non canonical strings', () => {
+    expect(() => uuid.parse('f81d4fae7dec11d0a76500a0c91e6bf6')).throws(SyntaxError)
+    expect(() => uuid.parse(`{${str}}`)).throws(SyntaxError)
+    expect(() => uuid.parse(str.replace('f8', 'g8'))).throws(SyntaxError)
+    expect(() => uuid.parse(str + '0')).throws(SyntaxError)
+  })
+  it('format', () => {
+    expect(uuid.format(value)).eq(str)
+    expect(uuid.format(255n)).eq('00000000-0000-0000-0000-0000000000ff')
+    expect(() => uuid.format(-1n)).throws(RangeError)
+    expect(() => uuid.format(2n ** 128n)).throws(RangeError)
+  })
+  it('bytes', () => {
+    const bytes = uuid.toBytes(value)
+    expect(bytes).toHaveLength(16)
+    expect(bytes[0]).eq(0xf8)
+    expect(bytes[15]).eq(0xf6)
+    expect(uuid.fromBytes(bytes)).eq(value)
+    const padded = new Uint8Array(18)
+    padded.set(bytes, 2)
+    expect(uuid.fromBytes(padded, 2)).eq(value)
+    expect(() => uuid.fromBytes(padded, 3)).throws(RangeError)
+  })
+  it('version and variant', () => {
+    expect(uuid.version(value)).eq(1)
+    expect(uuid.variant(value)).eq('rfc9562')
+    expect(uuid.version(uuid.parse('017f22e2-79b0-7cc3-98c4-dc0c0c07398f'))).eq(7)
+    expect(uuid.variant(0n)).eq('ncs')
+    expect(uuid.variant(uuid.parse('00000000-0000-0000-c000-000000000000'))).eq('microsoft')
+    expect(uuid.variant(2n ** 128n - 1n)).eq('future')
+  })
+})